@@ -1,6 +1,10 @@
 use proc_macro::TokenStream;
-use quote::{format_ident, quote, ToTokens};
-use syn::{parse_macro_input, Attribute, Data, DeriveInput, Fields, Path};
+use proc_macro2::Span;
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use syn::spanned::Spanned;
+use syn::meta::ParseNestedMeta;
+use syn::punctuated::Punctuated;
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Expr, Fields, LitStr, Path, Token};
 
 /// Derive macro for `validator::validate::Validate`.
 ///
@@ -14,12 +18,59 @@ use syn::{parse_macro_input, Attribute, Data, DeriveInput, Fields, Path};
 /// struct User {
 ///     #[validate(Email)]
 ///     email: String,
-///     #[validate(Positive)]
+///     // `message = "..."` sets a message template on the validator
+///     #[validate(Positive(message = "{field} must be positive"))]
 ///     age: i32,
+///     // `nested` runs the field's own `Validate` impl
+///     #[validate(nested)]
+///     address: Address,
+///     // `each(...)` applies its items to every item of a collection
+///     #[validate(each(Email))]
+///     aliases: Vec<String>,
+///     // `any(...)`, `all(...)` and `not(...)` combine validators
+///     #[validate(any(Email, Phone))]
+///     contact: String,
+///     // `custom(function = "...")` calls a `fn(&T) -> Result<(), ValidationError>`
+///     #[validate(custom(function = "check_nickname", code = "nickname"))]
+///     nickname: String,
+///     // `other = "..."` / `field = "..."` compare with a sibling field
+///     // (see `validator::prelude::CrossFieldValidator`)
+///     #[validate(MustMatch(other = "email"))]
+///     email_confirm: String,
 ///     // fields without `#[validate(...)]` are ignored
 ///     note: String,
 /// }
+///
+/// // `schema(function = "...")` on the struct runs a `fn(&Self) -> Result<(), ValidationError>`
+/// // after the field rules; its errors are reported at the struct level (`__all__`).
+/// // `schema(function = "...", async)` takes an async fn and only runs in `ValidateAsync`,
+/// // `skip_on_field_errors` skips the hook in `complete_validate` when a field failed.
+/// #[derive(Validate)]
+/// #[validate(schema(function = "check_dates"))]
+/// struct Booking {
+///     start: u32,
+///     end: u32,
+/// }
+///
+/// fn check_dates(booking: &Booking) -> Result<(), ValidationError> {
+///     if booking.end > booking.start { Ok(()) } else { Err(ValidationError::new("dates", "end before start")) }
+/// }
+///
+/// // On enums the fields of the active variant are validated; errors are
+/// // reported below the variant name (`Card.number`, `Paypal.0`).
+/// #[derive(Validate)]
+/// enum Payment {
+///     Card {
+///         #[validate(MinLength(12))]
+///         number: String,
+///     },
+///     Paypal(#[validate(Email)] String),
+///     Cash,
+/// }
 /// ```
+/// Unions are rejected with a compile error, as are malformed items, unknown
+/// keywords and duplicate rules. Validators that cannot validate the field type
+/// are reported at the item in the attribute.
 #[proc_macro_derive(Validate, attributes(validate))]
 pub fn derive_validate(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -27,233 +78,1017 @@ pub fn derive_validate(input: TokenStream) -> TokenStream {
     let generics = input.generics;
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
+    // Struct-level #[validate(...)] items: schema hooks running after the field
+    // rules and the optional context type
+    let spec = match find_struct_spec(&input.attrs) {
+        Ok(spec) => spec,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let ctx = spec.context.is_some();
+    let shape = match collect_shape(&input.data) {
+        Ok(shape) => shape,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
     // Generate short-circuit validation from field-level #[validate(...)] annotations
-    let validate_stmts = build_validate_stmts(&input.data);
+    let mut validate_stmts = build_validate_stmts(&shape, ctx);
+    validate_stmts.extend(build_schema_validate_stmts(&spec.schemas, false, ctx));
 
     // Generate error-collecting validation from field-level #[validate(...)] annotations
-    let complete_validate_stmts = build_complete_validate_stmts(&input.data);
+    let mut complete_validate_stmts = build_complete_validate_stmts(&shape, ctx);
+    complete_validate_stmts.extend(build_schema_complete_validate_stmts(&spec.schemas, false, ctx));
+
+    let validate_body = tracked_validate_body(&validate_stmts);
+    let complete_validate_body = tracked_complete_validate_body(&complete_validate_stmts);
 
-    let guard_mod = format_ident!("__validate_guard_{}", ident);
+    // Context-free structs implement `ValidateWith<C>` for any context by
+    // ignoring it, so they can be nested into structs with a context.
+    let mut any_ctx_generics = generics.clone();
+    any_ctx_generics.params.push(syn::parse_quote!(__C: ?Sized));
+    let (any_ctx_impl_generics, _, _) = any_ctx_generics.split_for_impl();
 
     // Prepare optional async impl block depending on this crate's feature.
     #[cfg(feature = "async")]
     let async_impl_block = {
-        let async_validate_stmts = build_async_validate_stmts(&input.data);
-        let async_complete_validate_stmts = build_async_complete_validate_stmts(&input.data);
-        quote! {
-            impl #impl_generics ::validator::validate::ValidateAsync for #ident #ty_generics #where_clause {
-                fn validate_async(&self) -> ::std::pin::Pin<::std::boxed::Box<dyn ::core::future::Future<Output = Result<(), ::validator::prelude::ValidationError>> + '_>> {
-                    ::std::boxed::Box::pin(async move {
-                        if #guard_mod::enter() {
-                            let __res: Result<(), ::validator::prelude::ValidationError> = async {
-                                #(#async_validate_stmts)*
-                                Ok(())
-                            }.await;
-                            #guard_mod::exit();
-                            __res
-                        } else {
-                            // Re-entrant call detected; short-circuit to avoid infinite recursion
-                            Ok(())
+        let mut async_validate_stmts = build_async_validate_stmts(&shape, ctx);
+        async_validate_stmts.extend(build_schema_validate_stmts(&spec.schemas, true, ctx));
+        let mut async_complete_validate_stmts = build_async_complete_validate_stmts(&shape, ctx);
+        async_complete_validate_stmts.extend(build_schema_complete_validate_stmts(&spec.schemas, true, ctx));
+        let async_validate_body = tracked_async_validate_body(&async_validate_stmts);
+        let async_complete_validate_body = tracked_async_complete_validate_body(&async_complete_validate_stmts);
+        // The boxed `ValidateAsync` and `ValidateWithAsync` are implemented by
+        // boxing the futures of the static traits.
+        match &spec.context {
+            Some(ctx_ty) => quote! {
+                impl #impl_generics ::validator::validate::StaticValidateWithAsync<#ctx_ty> for #ident #ty_generics #where_clause {
+                    fn validate_with_static<'__a>(&'__a self, __ctx: &'__a #ctx_ty) -> impl ::core::future::Future<Output = Result<(), ::validator::prelude::ValidationError>> + '__a {
+                        async move {
+                            let mut __state = ::validator::prelude::ValidationState::new();
+                            ::validator::validate::StaticValidateWithAsync::validate_with_static_in(self, __ctx, &mut __state).await
                         }
-                    })
-                }
-
-                fn complete_validate_async(&self) -> ::std::pin::Pin<::std::boxed::Box<dyn ::core::future::Future<Output = Result<(), ::std::collections::HashMap<::std::string::String, ::std::vec::Vec<::validator::prelude::ValidationError>>>> + '_>> {
-                    ::std::boxed::Box::pin(async move {
-                        if #guard_mod::enter() {
-                            let __res: Result<(), ::std::collections::HashMap<::std::string::String, ::std::vec::Vec<::validator::prelude::ValidationError>>> = async {
-                                let mut __errors: ::std::collections::HashMap<::std::string::String, ::std::vec::Vec<::validator::prelude::ValidationError>> = ::std::collections::HashMap::new();
-                                #(#async_complete_validate_stmts)*
-                                if __errors.is_empty() { Ok(()) } else { Err(__errors) }
-                            }.await;
-                            #guard_mod::exit();
-                            __res
-                        } else {
-                            // Re-entrant call detected; short-circuit to avoid infinite recursion
-                            Ok(())
+                    }
+
+                    fn complete_validate_with_static<'__a>(&'__a self, __ctx: &'__a #ctx_ty) -> impl ::core::future::Future<Output = Result<(), ::validator::prelude::ValidationErrors>> + '__a {
+                        async move {
+                            let mut __state = ::validator::prelude::ValidationState::new();
+                            ::validator::validate::StaticValidateWithAsync::complete_validate_with_static_in(self, __ctx, &mut __state).await
                         }
-                    })
+                    }
+
+                    fn validate_with_static_in<'__a>(&'__a self, __ctx: &'__a #ctx_ty, __state: &'__a mut ::validator::prelude::ValidationState) -> impl ::core::future::Future<Output = Result<(), ::validator::prelude::ValidationError>> + '__a {
+                        #async_validate_body
+                    }
+
+                    fn complete_validate_with_static_in<'__a>(&'__a self, __ctx: &'__a #ctx_ty, __state: &'__a mut ::validator::prelude::ValidationState) -> impl ::core::future::Future<Output = Result<(), ::validator::prelude::ValidationErrors>> + '__a {
+                        #async_complete_validate_body
+                    }
                 }
-            }
+
+                impl #impl_generics ::validator::validate::ValidateWithAsync<#ctx_ty> for #ident #ty_generics #where_clause {
+                    fn validate_with_async<'__a>(&'__a self, __ctx: &'__a #ctx_ty) -> ::std::pin::Pin<::std::boxed::Box<dyn ::core::future::Future<Output = Result<(), ::validator::prelude::ValidationError>> + '__a>> {
+                        ::std::boxed::Box::pin(::validator::validate::StaticValidateWithAsync::validate_with_static(self, __ctx))
+                    }
+
+                    fn complete_validate_with_async<'__a>(&'__a self, __ctx: &'__a #ctx_ty) -> ::std::pin::Pin<::std::boxed::Box<dyn ::core::future::Future<Output = Result<(), ::validator::prelude::ValidationErrors>> + '__a>> {
+                        ::std::boxed::Box::pin(::validator::validate::StaticValidateWithAsync::complete_validate_with_static(self, __ctx))
+                    }
+
+                    fn validate_with_async_in<'__a>(&'__a self, __ctx: &'__a #ctx_ty, __state: &'__a mut ::validator::prelude::ValidationState) -> ::std::pin::Pin<::std::boxed::Box<dyn ::core::future::Future<Output = Result<(), ::validator::prelude::ValidationError>> + '__a>> {
+                        ::std::boxed::Box::pin(::validator::validate::StaticValidateWithAsync::validate_with_static_in(self, __ctx, __state))
+                    }
+
+                    fn complete_validate_with_async_in<'__a>(&'__a self, __ctx: &'__a #ctx_ty, __state: &'__a mut ::validator::prelude::ValidationState) -> ::std::pin::Pin<::std::boxed::Box<dyn ::core::future::Future<Output = Result<(), ::validator::prelude::ValidationErrors>> + '__a>> {
+                        ::std::boxed::Box::pin(::validator::validate::StaticValidateWithAsync::complete_validate_with_static_in(self, __ctx, __state))
+                    }
+                }
+            },
+            None => quote! {
+                impl #impl_generics ::validator::validate::StaticValidateAsync for #ident #ty_generics #where_clause {
+                    fn validate_static<'__a>(&'__a self) -> impl ::core::future::Future<Output = Result<(), ::validator::prelude::ValidationError>> + '__a {
+                        async move {
+                            let mut __state = ::validator::prelude::ValidationState::new();
+                            ::validator::validate::StaticValidateAsync::validate_static_in(self, &mut __state).await
+                        }
+                    }
+
+                    fn complete_validate_static<'__a>(&'__a self) -> impl ::core::future::Future<Output = Result<(), ::validator::prelude::ValidationErrors>> + '__a {
+                        async move {
+                            let mut __state = ::validator::prelude::ValidationState::new();
+                            ::validator::validate::StaticValidateAsync::complete_validate_static_in(self, &mut __state).await
+                        }
+                    }
+
+                    fn validate_static_in<'__a>(&'__a self, __state: &'__a mut ::validator::prelude::ValidationState) -> impl ::core::future::Future<Output = Result<(), ::validator::prelude::ValidationError>> + '__a {
+                        #async_validate_body
+                    }
+
+                    fn complete_validate_static_in<'__a>(&'__a self, __state: &'__a mut ::validator::prelude::ValidationState) -> impl ::core::future::Future<Output = Result<(), ::validator::prelude::ValidationErrors>> + '__a {
+                        #async_complete_validate_body
+                    }
+                }
+
+                impl #any_ctx_impl_generics ::validator::validate::StaticValidateWithAsync<__C> for #ident #ty_generics #where_clause {
+                    fn validate_with_static<'__a>(&'__a self, _ctx: &'__a __C) -> impl ::core::future::Future<Output = Result<(), ::validator::prelude::ValidationError>> + '__a {
+                        ::validator::validate::StaticValidateAsync::validate_static(self)
+                    }
+
+                    fn complete_validate_with_static<'__a>(&'__a self, _ctx: &'__a __C) -> impl ::core::future::Future<Output = Result<(), ::validator::prelude::ValidationErrors>> + '__a {
+                        ::validator::validate::StaticValidateAsync::complete_validate_static(self)
+                    }
+
+                    fn validate_with_static_in<'__a>(&'__a self, _ctx: &'__a __C, __state: &'__a mut ::validator::prelude::ValidationState) -> impl ::core::future::Future<Output = Result<(), ::validator::prelude::ValidationError>> + '__a {
+                        ::validator::validate::StaticValidateAsync::validate_static_in(self, __state)
+                    }
+
+                    fn complete_validate_with_static_in<'__a>(&'__a self, _ctx: &'__a __C, __state: &'__a mut ::validator::prelude::ValidationState) -> impl ::core::future::Future<Output = Result<(), ::validator::prelude::ValidationErrors>> + '__a {
+                        ::validator::validate::StaticValidateAsync::complete_validate_static_in(self, __state)
+                    }
+                }
+
+                impl #impl_generics ::validator::validate::ValidateAsync for #ident #ty_generics #where_clause {
+                    fn validate_async<'__a>(&'__a self) -> ::std::pin::Pin<::std::boxed::Box<dyn ::core::future::Future<Output = Result<(), ::validator::prelude::ValidationError>> + '__a>> {
+                        ::std::boxed::Box::pin(::validator::validate::StaticValidateAsync::validate_static(self))
+                    }
+
+                    fn complete_validate_async<'__a>(&'__a self) -> ::std::pin::Pin<::std::boxed::Box<dyn ::core::future::Future<Output = Result<(), ::validator::prelude::ValidationErrors>> + '__a>> {
+                        ::std::boxed::Box::pin(::validator::validate::StaticValidateAsync::complete_validate_static(self))
+                    }
+
+                    fn validate_async_in<'__a>(&'__a self, __state: &'__a mut ::validator::prelude::ValidationState) -> ::std::pin::Pin<::std::boxed::Box<dyn ::core::future::Future<Output = Result<(), ::validator::prelude::ValidationError>> + '__a>> {
+                        ::std::boxed::Box::pin(::validator::validate::StaticValidateAsync::validate_static_in(self, __state))
+                    }
+
+                    fn complete_validate_async_in<'__a>(&'__a self, __state: &'__a mut ::validator::prelude::ValidationState) -> ::std::pin::Pin<::std::boxed::Box<dyn ::core::future::Future<Output = Result<(), ::validator::prelude::ValidationErrors>> + '__a>> {
+                        ::std::boxed::Box::pin(::validator::validate::StaticValidateAsync::complete_validate_static_in(self, __state))
+                    }
+                }
+
+                impl #any_ctx_impl_generics ::validator::validate::ValidateWithAsync<__C> for #ident #ty_generics #where_clause {
+                    fn validate_with_async<'__a>(&'__a self, _ctx: &'__a __C) -> ::std::pin::Pin<::std::boxed::Box<dyn ::core::future::Future<Output = Result<(), ::validator::prelude::ValidationError>> + '__a>> {
+                        ::validator::validate::ValidateAsync::validate_async(self)
+                    }
+
+                    fn complete_validate_with_async<'__a>(&'__a self, _ctx: &'__a __C) -> ::std::pin::Pin<::std::boxed::Box<dyn ::core::future::Future<Output = Result<(), ::validator::prelude::ValidationErrors>> + '__a>> {
+                        ::validator::validate::ValidateAsync::complete_validate_async(self)
+                    }
+
+                    fn validate_with_async_in<'__a>(&'__a self, _ctx: &'__a __C, __state: &'__a mut ::validator::prelude::ValidationState) -> ::std::pin::Pin<::std::boxed::Box<dyn ::core::future::Future<Output = Result<(), ::validator::prelude::ValidationError>> + '__a>> {
+                        ::validator::validate::ValidateAsync::validate_async_in(self, __state)
+                    }
+
+                    fn complete_validate_with_async_in<'__a>(&'__a self, _ctx: &'__a __C, __state: &'__a mut ::validator::prelude::ValidationState) -> ::std::pin::Pin<::std::boxed::Box<dyn ::core::future::Future<Output = Result<(), ::validator::prelude::ValidationErrors>> + '__a>> {
+                        ::validator::validate::ValidateAsync::complete_validate_async_in(self, __state)
+                    }
+                }
+            },
         }
     };
     #[cfg(not(feature = "async"))]
     let async_impl_block = quote! {};
 
-    let codgen = quote! {
-        #[allow(non_snake_case, non_camel_case_types, unused_qualifications)]
-        mod #guard_mod {
-            use std::cell::Cell;
-            thread_local! { static DEPTH: Cell<usize> = Cell::new(0); }
-            #[inline]
-            pub fn enter() -> bool {
-                DEPTH.with(|d| {
-                    let n = d.get();
-                    if n == 0 { d.set(1); true } else { false }
-                })
+    // Rule descriptors of the annotated fields, see `validator::rules`
+    let field_rules = build_field_rules(&shape, &spec.schemas, spec.context.as_ref());
+    let rules_impl_block = quote! {
+        impl #impl_generics ::validator::rules::ValidationRules for #ident #ty_generics #where_clause {
+            fn validation_rules() -> ::std::vec::Vec<::validator::rules::FieldRules> {
+                ::std::vec![#(#field_rules),*]
             }
-            #[inline]
-            pub fn exit() {
-                DEPTH.with(|d| d.set(d.get().saturating_sub(1)))
+        }
+    };
+
+    // JSON Schema of the annotated fields, see `validator::json_schema`
+    #[cfg(feature = "json_schema")]
+    let json_schema_block = {
+        let schema = data_json_schema(&input.data, spec.context.as_ref());
+        let name = ident.to_string();
+        quote! {
+            impl #impl_generics ::validator::json_schema::JsonSchema for #ident #ty_generics #where_clause {
+                fn json_schema() -> ::validator::json_schema::__private::serde_json::Value {
+                    ::validator::json_schema::__private::definition::<Self>(#name, || #schema)
+                }
             }
         }
+    };
+    #[cfg(not(feature = "json_schema"))]
+    let json_schema_block = quote! {};
 
-        impl #impl_generics ::validator::validate::Validate for #ident #ty_generics #where_clause {
-            fn validate(&self) -> Result<(), ::validator::prelude::ValidationError> {
-                if #guard_mod::enter() {
-                    let __res: Result<(), ::validator::prelude::ValidationError> = (|| {
-                        #(#validate_stmts)*
-                        Ok(())
-                    })();
-                    #guard_mod::exit();
-                    __res
-                } else {
-                    // Re-entrant call detected; short-circuit to avoid infinite recursion
-                    Ok(())
+    // Structs with `#[validate(context = "...")]` can only be validated with
+    // their context, the others implement `Validate`.
+    let impl_block = match &spec.context {
+        Some(ctx_ty) => quote! {
+            impl #impl_generics ::validator::validate::ValidateWith<#ctx_ty> for #ident #ty_generics #where_clause {
+                fn validate_with(&self, __ctx: &#ctx_ty) -> Result<(), ::validator::prelude::ValidationError> {
+                    ::validator::validate::ValidateWith::validate_with_in(self, __ctx, &mut ::validator::prelude::ValidationState::new())
+                }
+
+                fn complete_validate_with(&self, __ctx: &#ctx_ty) -> Result<(), ::validator::prelude::ValidationErrors> {
+                    ::validator::validate::ValidateWith::complete_validate_with_in(self, __ctx, &mut ::validator::prelude::ValidationState::new())
+                }
+
+                fn validate_with_in(&self, __ctx: &#ctx_ty, __state: &mut ::validator::prelude::ValidationState) -> Result<(), ::validator::prelude::ValidationError> {
+                    #validate_body
+                }
+
+                fn complete_validate_with_in(&self, __ctx: &#ctx_ty, __state: &mut ::validator::prelude::ValidationState) -> Result<(), ::validator::prelude::ValidationErrors> {
+                    #complete_validate_body
                 }
             }
+        },
+        None => quote! {
+            impl #impl_generics ::validator::validate::Validate for #ident #ty_generics #where_clause {
+                fn validate(&self) -> Result<(), ::validator::prelude::ValidationError> {
+                    ::validator::validate::Validate::validate_in(self, &mut ::validator::prelude::ValidationState::new())
+                }
 
-            fn complete_validate(&self) -> Result<(), ::std::collections::HashMap<::std::string::String, ::std::vec::Vec<::validator::prelude::ValidationError>>> {
-                if #guard_mod::enter() {
-                    let __res: Result<(), ::std::collections::HashMap<::std::string::String, ::std::vec::Vec<::validator::prelude::ValidationError>>> = (|| {
-                        let mut __errors: ::std::collections::HashMap<::std::string::String, ::std::vec::Vec<::validator::prelude::ValidationError>> = ::std::collections::HashMap::new();
-                        #(#complete_validate_stmts)*
-                        if __errors.is_empty() { Ok(()) } else { Err(__errors) }
-                    })();
-                    #guard_mod::exit();
-                    __res
-                } else {
-                    // Re-entrant call detected; short-circuit to avoid infinite recursion
-                    Ok(())
+                fn complete_validate(&self) -> Result<(), ::validator::prelude::ValidationErrors> {
+                    ::validator::validate::Validate::complete_validate_in(self, &mut ::validator::prelude::ValidationState::new())
+                }
+
+                fn validate_in(&self, __state: &mut ::validator::prelude::ValidationState) -> Result<(), ::validator::prelude::ValidationError> {
+                    #validate_body
+                }
+
+                fn complete_validate_in(&self, __state: &mut ::validator::prelude::ValidationState) -> Result<(), ::validator::prelude::ValidationErrors> {
+                    #complete_validate_body
                 }
             }
-        }
+
+            impl #any_ctx_impl_generics ::validator::validate::ValidateWith<__C> for #ident #ty_generics #where_clause {
+                fn validate_with(&self, _ctx: &__C) -> Result<(), ::validator::prelude::ValidationError> {
+                    ::validator::validate::Validate::validate(self)
+                }
+
+                fn complete_validate_with(&self, _ctx: &__C) -> Result<(), ::validator::prelude::ValidationErrors> {
+                    ::validator::validate::Validate::complete_validate(self)
+                }
+
+                fn validate_with_in(&self, _ctx: &__C, __state: &mut ::validator::prelude::ValidationState) -> Result<(), ::validator::prelude::ValidationError> {
+                    ::validator::validate::Validate::validate_in(self, __state)
+                }
+
+                fn complete_validate_with_in(&self, _ctx: &__C, __state: &mut ::validator::prelude::ValidationState) -> Result<(), ::validator::prelude::ValidationErrors> {
+                    ::validator::validate::Validate::complete_validate_in(self, __state)
+                }
+            }
+        },
+    };
+
+    let codgen = quote! {
+        #impl_block
 
         #async_impl_block
+
+        #rules_impl_block
+
+        #json_schema_block
     };
     // For Debug purpose:
     // eprintln!("{}", codgen.to_string());
     codgen.into()
 }
 
+/// Body of a short-circuit `validate_in` method running `stmts` unless `self`
+/// is already being validated further up (a cycle).
+fn tracked_validate_body(stmts: &[proc_macro2::TokenStream]) -> proc_macro2::TokenStream {
+    quote! {
+        if !__state.enter(self) {
+            return __state.cycle();
+        }
+        let __res: Result<(), ::validator::prelude::ValidationError> = (|| {
+            #(#stmts)*
+            Ok(())
+        })();
+        __state.exit(self);
+        __res
+    }
+}
+
+/// Body of an error-collecting `complete_validate_in` method running `stmts`
+/// unless `self` is already being validated further up (a cycle).
+fn tracked_complete_validate_body(stmts: &[proc_macro2::TokenStream]) -> proc_macro2::TokenStream {
+    quote! {
+        if !__state.enter(self) {
+            return __state.complete_cycle();
+        }
+        let __res: Result<(), ::validator::prelude::ValidationErrors> = (|| {
+            let mut __errors = ::validator::prelude::ValidationErrors::new();
+            #(#stmts)*
+            if __errors.is_empty() { Ok(()) } else { Err(__errors) }
+        })();
+        __state.exit(self);
+        __res
+    }
+}
+
+/// Async counterpart to `tracked_validate_body`
+#[cfg(feature = "async")]
+fn tracked_async_validate_body(stmts: &[proc_macro2::TokenStream]) -> proc_macro2::TokenStream {
+    quote! {
+        async move {
+            if !__state.enter(self) {
+                return __state.cycle();
+            }
+            let __res: Result<(), ::validator::prelude::ValidationError> = async {
+                #(#stmts)*
+                Ok(())
+            }.await;
+            __state.exit(self);
+            __res
+        }
+    }
+}
+
+/// Async counterpart to `tracked_complete_validate_body`
+#[cfg(feature = "async")]
+fn tracked_async_complete_validate_body(stmts: &[proc_macro2::TokenStream]) -> proc_macro2::TokenStream {
+    quote! {
+        async move {
+            if !__state.enter(self) {
+                return __state.complete_cycle();
+            }
+            let __res: Result<(), ::validator::prelude::ValidationErrors> = async {
+                let mut __errors = ::validator::prelude::ValidationErrors::new();
+                #(#stmts)*
+                if __errors.is_empty() { Ok(()) } else { Err(__errors) }
+            }.await;
+            __state.exit(self);
+            __res
+        }
+    }
+}
+
 /// Build short-circuit validate statements for each field annotated with
 /// `#[validate(...)]`. Works on the inner items of the annotation, turning
 /// each validator item into a validator instance and a `validate(&field)` call.
-fn build_validate_stmts(data: &Data) -> Vec<proc_macro2::TokenStream> {
-    collect_field_specs(data, /*with_keys=*/ false)
-        .into_iter()
-        .flat_map(|spec| build_validate_for_accessor(spec.accessor, &spec.vpaths))
-        .collect()
+fn build_validate_stmts(shape: &Shape, ctx: bool) -> Vec<proc_macro2::TokenStream> {
+    build_shape_stmts(shape, false, |spec| build_validate_for_accessor(spec.accessor.clone(), &spec.key, &spec.vpaths, ctx))
 }
 
 /// Build error-collecting validate statements for each field annotated with
-/// `#[validate(...)]`. Uses the field name (or tuple index) as the error-map key
+/// `#[validate(...)]`. Uses the field name (or tuple index) as the path segment
 /// and iterates over each validator item inside the annotation.
-fn build_complete_validate_stmts(data: &Data) -> Vec<proc_macro2::TokenStream> {
-    collect_field_specs(data, /*with_keys=*/ true)
-        .into_iter()
-        .flat_map(|spec| {
-            let key = spec.key.expect("key must be present when with_keys=true");
-            build_complete_validate_for_accessor(spec.accessor, key, &spec.vpaths)
-        })
-        .collect()
+fn build_complete_validate_stmts(shape: &Shape, ctx: bool) -> Vec<proc_macro2::TokenStream> {
+    build_shape_stmts(shape, true, |spec| {
+        vec![wrap_field_errors(&spec.key, build_complete_validate_for_accessor(spec.accessor.clone(), &spec.key, 0, &spec.vpaths, ctx))]
+    })
 }
 
 /// Build async short-circuit validate statements for each field annotated with
 /// `#[validate(...)]`. Works similarly to the sync version but awaits each
 /// validator's `validate_async`.
-fn build_async_validate_stmts(data: &Data) -> Vec<proc_macro2::TokenStream> {
-    collect_field_specs(data, /*with_keys=*/ false)
-        .into_iter()
-        .flat_map(|spec| build_async_validate_for_accessor(spec.accessor, &spec.vpaths))
-        .collect()
+#[cfg(feature = "async")]
+fn build_async_validate_stmts(shape: &Shape, ctx: bool) -> Vec<proc_macro2::TokenStream> {
+    build_shape_stmts(shape, false, |spec| build_async_validate_for_accessor(spec.accessor.clone(), &spec.key, &spec.vpaths, ctx))
 }
 
 /// Build async error-collecting validate statements for each annotated field.
-fn build_async_complete_validate_stmts(data: &Data) -> Vec<proc_macro2::TokenStream> {
-    collect_field_specs(data, /*with_keys=*/ true)
-        .into_iter()
-        .flat_map(|spec| {
-            let key = spec.key.expect("key must be present when with_keys=true");
-            build_async_complete_validate_for_accessor(spec.accessor, key, &spec.vpaths)
+/// The fields of a struct are validated concurrently with `futures::join!`,
+/// each by a task with a fork of the state and its own `__errors`; their
+/// errors are merged in field order (see `validator::concurrency`).
+#[cfg(feature = "async")]
+fn build_async_complete_validate_stmts(shape: &Shape, ctx: bool) -> Vec<proc_macro2::TokenStream> {
+    let stmts = build_shape_stmts(shape, true, |spec| {
+        vec![wrap_field_errors(&spec.key, build_async_complete_validate_for_accessor(spec.accessor.clone(), &spec.key, 0, &spec.vpaths, ctx))]
+    });
+    if !matches!(shape, Shape::Struct(_)) || stmts.len() < 2 {
+        return stmts;
+    }
+    let tasks: Vec<_> = (0..stmts.len()).map(|i| format_ident!("__task_{}", i)).collect();
+    let results: Vec<_> = (0..stmts.len()).map(syn::Index::from).collect();
+    vec![quote! {
+        #(
+            let #tasks = move |mut __fork: ::validator::prelude::ValidationState| async move {
+                let __state = &mut __fork;
+                let mut __errors = ::validator::prelude::ValidationErrors::new();
+                #stmts
+                (__errors, __fork)
+            };
+        )*
+        if __state.options().validates_fields_concurrently() {
+            let __limiter = ::validator::concurrency::Limiter::new(__state.options().max_concurrency);
+            let __results = ::validator::futures::join!(#(__limiter.run(#tasks(__state.fork()))),*);
+            for (__field_errors, __fork) in [#(__results.#results),*] {
+                __state.join(__fork);
+                __errors.merge(__field_errors);
+            }
+        } else {
+            #(
+                let (__field_errors, __fork) = #tasks(__state.fork()).await;
+                __state.join(__fork);
+                __errors.merge(__field_errors);
+            )*
+        }
+    }]
+}
+
+/// Combine the statements `field_stmts` builds for every field, run when the
+/// field passes the field filters of the options. For enums the
+/// statements of a variant run in its match arm; with `nest_variants` set
+/// (error-collecting mode) the errors of the variant are attached to `__errors`
+/// below the variant name.
+fn build_shape_stmts(
+    shape: &Shape,
+    nest_variants: bool,
+    field_stmts: impl Fn(&FieldSpec) -> Vec<proc_macro2::TokenStream>,
+) -> Vec<proc_macro2::TokenStream> {
+    // Fields left out by `skip_fields`/`only_fields` are skipped
+    let field_stmts = |spec: &FieldSpec| {
+        let stmts = field_stmts(spec);
+        if stmts.is_empty() {
+            return stmts;
+        }
+        let key = &spec.key;
+        vec![quote! { if __state.validates_field(#key) { #(#stmts)* } }]
+    };
+    match shape {
+        Shape::Struct(fields) => fields.iter().flat_map(&field_stmts).collect(),
+        // Values of empty enums cannot exist
+        Shape::Enum(variants) if variants.is_empty() => Vec::new(),
+        Shape::Enum(variants) => {
+            let arms = variants.iter().map(|variant| {
+                let pattern = &variant.pattern;
+                let stmts: Vec<_> = variant.fields.iter().flat_map(&field_stmts).collect();
+                let key = &variant.key;
+                if nest_variants && !stmts.is_empty() {
+                    quote! {
+                        #pattern => {
+                            let mut __variant_errors = ::validator::prelude::ValidationErrors::new();
+                            {
+                                let __errors = &mut __variant_errors;
+                                #(#stmts)*
+                            }
+                            __errors.nest(::validator::prelude::PathSegment::field(#key), __variant_errors);
+                        }
+                    }
+                } else {
+                    quote! { #pattern => { #(#stmts)* } }
+                }
+            });
+            vec![quote! { match self { #(#arms)* } }]
+        }
+    }
+}
+
+/// Collect the errors of a single field into its own `ValidationErrors` and
+/// attach them to `__errors` under the field name (or tuple index).
+fn wrap_field_errors(key: &str, stmts: Vec<proc_macro2::TokenStream>) -> proc_macro2::TokenStream {
+    let target = errors_ident(0);
+    quote! {
+        {
+            let mut #target = ::validator::prelude::ValidationErrors::new();
+            #(#stmts)*
+            __errors.nest(::validator::prelude::PathSegment::field(#key), #target);
+        }
+    }
+}
+
+/// A struct-level `#[validate(schema(...))]` hook:
+/// - function: path of a `fn(&Self) -> Result<(), ValidationError>` (or of an
+///   async fn with the same signature when `is_async` is set)
+/// - is_async: only run by `ValidateAsync`, awaited
+/// - skip_on_field_errors: in error-collecting mode, skip the hook when a field
+///   rule already failed
+/// - use_context: pass the validation context as second argument
+struct SchemaSpec {
+    function: Path,
+    is_async: bool,
+    skip_on_field_errors: bool,
+    use_context: bool,
+}
+
+/// The struct-level `#[validate(...)]` items:
+/// - schemas: `schema(...)` hooks
+/// - context: type given with `context = "..."`; the struct then implements
+///   `ValidateWith<Context>` instead of `Validate` and passes the context on to
+///   validators and nested values
+struct StructSpec {
+    schemas: Vec<SchemaSpec>,
+    context: Option<syn::Type>,
+}
+
+/// Parse the struct-level `#[validate(...)]` attributes, e.g.
+/// `#[validate(schema(function = "check_dates"), context = "Tenant")]`.
+fn find_struct_spec(attrs: &[Attribute]) -> syn::Result<StructSpec> {
+    let mut spec = StructSpec { schemas: Vec::new(), context: None };
+    for attr in attrs {
+        if !attr.path().is_ident("validate") { continue; }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("context") {
+                let value = meta.value()?;
+                spec.context = Some(if value.peek(LitStr) {
+                    value.parse::<LitStr>()?.parse::<syn::Type>()?
+                } else {
+                    value.parse::<syn::Type>()?
+                });
+                return Ok(());
+            }
+            if !meta.path.is_ident("schema") {
+                return Err(meta.error("expected `schema(function = \"...\")` or `context = \"...\"`"));
+            }
+            let mut function = None;
+            let mut is_async = false;
+            let mut skip_on_field_errors = false;
+            let mut use_context = false;
+            meta.parse_nested_meta(|meta| {
+                if meta.path.is_ident("function") {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    function = Some(lit.parse::<Path>()?);
+                } else if meta.path.is_ident("async") {
+                    is_async = true;
+                } else if meta.path.is_ident("skip_on_field_errors") {
+                    skip_on_field_errors = true;
+                } else if meta.path.is_ident("use_context") {
+                    use_context = true;
+                } else {
+                    return Err(meta.error("expected `function`, `async`, `skip_on_field_errors` or `use_context`"));
+                }
+                Ok(())
+            })?;
+            let Some(function) = function else {
+                return Err(meta.error("missing `function = \"...\"`"));
+            };
+            spec.schemas.push(SchemaSpec { function, is_async, skip_on_field_errors, use_context });
+            Ok(())
+        })?;
+    }
+    if let (None, Some(schema)) = (&spec.context, spec.schemas.iter().find(|schema| schema.use_context)) {
+        return Err(syn::Error::new_spanned(&schema.function, "`use_context` requires `#[validate(context = \"...\")]` on the struct"));
+    }
+    Ok(spec)
+}
+
+/// Emit short-circuiting calls of the schema hooks. Async hooks are only
+/// called (and awaited) when `in_async` is set.
+fn build_schema_validate_stmts(schemas: &[SchemaSpec], in_async: bool, ctx: bool) -> Vec<proc_macro2::TokenStream> {
+    schemas
+        .iter()
+        .filter(|schema| in_async || !schema.is_async)
+        .map(|schema| {
+            let call = schema_call(schema, ctx);
+            quote! { #call?; }
         })
         .collect()
 }
 
+/// Expression calling a schema hook, awaited for async hooks.
+fn schema_call(schema: &SchemaSpec, ctx: bool) -> proc_macro2::TokenStream {
+    let function = &schema.function;
+    let await_token = schema.is_async.then(|| quote! { .await });
+    let ctx_arg = (ctx && schema.use_context).then(|| quote! { , __ctx });
+    quote! { #function(self #ctx_arg) #await_token }
+}
+
+/// Emit error-collecting calls of the schema hooks; their errors are reported
+/// as struct-level errors (`__all__`).
+fn build_schema_complete_validate_stmts(schemas: &[SchemaSpec], in_async: bool, ctx: bool) -> Vec<proc_macro2::TokenStream> {
+    let calls: Vec<_> = schemas
+        .iter()
+        .filter(|schema| in_async || !schema.is_async)
+        .map(|schema| {
+            let call = schema_call(schema, ctx);
+            let call = quote! {
+                if !__state.is_full() {
+                    if let Err(e) = #call {
+                        __errors.add_error(e);
+                        __state.record_error();
+                    }
+                }
+            };
+            if schema.skip_on_field_errors {
+                quote! { if __field_errors_empty { #call } }
+            } else {
+                call
+            }
+        })
+        .collect();
+    if !schemas.iter().any(|schema| schema.skip_on_field_errors && (in_async || !schema.is_async)) {
+        return calls;
+    }
+    let mut stmts = vec![quote! { let __field_errors_empty = __errors.is_empty(); }];
+    stmts.extend(calls);
+    stmts
+}
+
+/// Name of the `ValidationErrors` collecting the errors of a value at the given
+/// `each(...)` depth (0 for the field itself).
+fn errors_ident(depth: usize) -> proc_macro2::Ident {
+    format_ident!("__errors_{}", depth)
+}
+
 /// Describes how a single field with a `#[validate(...)]` annotation should be
 /// expanded:
-/// - accessor: `self.field` or `self.N` for tuple structs
-/// - key: optional error-map key (field name or index as string)
-/// - vpaths: validator constructor expressions taken from the items of
-///   the `#[validate(...)]` annotation
+/// - accessor: `self.field` or `self.N` for tuple structs, `(*__field_name)`
+///   for the bound fields of enum variants
+/// - key: field name or index as string, used as path segment and `field` param
+/// - vpaths: rules taken from the items of the `#[validate(...)]` annotation
 struct FieldSpec {
     accessor: proc_macro2::TokenStream,
-    key: Option<String>,
-    vpaths: Vec<proc_macro2::TokenStream>,
+    ty: syn::Type,
+    key: String,
+    vpaths: Vec<Rule>,
+}
+
+/// A single item of a `#[validate(...)]` annotation.
+/// - Validator: constructor expression of a `Validator<T>` for the field and
+///   the path of the validator (for diagnostics)
+/// - Nested: the `nested` keyword, delegating to the field's own `Validate` impl
+/// - Each: the `each(...)` keyword, applying its inner rules to every item of a
+///   collection (see `validator::collection::Each`)
+/// - CrossField: a `CrossFieldValidator<T, U>` and the sibling field it
+///   compares with
+/// - Custom: the `custom(function = "...")` keyword, calling a plain function or
+///   closure on the field
+///
+/// Keyword items keep their span so that errors point at the attribute.
+enum Rule {
+    Validator(proc_macro2::TokenStream, Path),
+    Custom(Box<CustomRule>),
+    CrossField(Box<CrossFieldRule>),
+    Nested(Span),
+    Each(Vec<Rule>, Span),
+}
+
+/// A validator item naming a sibling field with `other = "..."`/`field = "..."`:
+/// - validator / path: constructor expression and path of the
+///   `CrossFieldValidator<T, U>`
+/// - member / name: the sibling field
+/// - sibling: accessor expression of the sibling (see `resolve_siblings`)
+/// - sibling_ty: type of the sibling, when it is a field of the same value
+struct CrossFieldRule {
+    validator: proc_macro2::TokenStream,
+    path: Path,
+    member: syn::Member,
+    name: String,
+    sibling: proc_macro2::TokenStream,
+    sibling_ty: Option<syn::Type>,
+}
+
+/// A `custom(...)` item:
+/// - function: a `fn(&T) -> Result<(), ValidationError>` path (also accepted as
+///   string literal) or a closure; an async fn when `is_async` is set
+/// - code / message: replace the code and message of the returned error
+/// - use_context: pass the validation context as second argument
+struct CustomRule {
+    function: Expr,
+    code: Option<LitStr>,
+    message: Option<Expr>,
+    is_async: bool,
+    use_context: bool,
+}
+
+/// Parse the arguments of a `custom(...)` item.
+fn parse_custom_rule(meta: &ParseNestedMeta) -> syn::Result<CustomRule> {
+    let mut function = None;
+    let mut code = None;
+    let mut message = None;
+    let mut is_async = false;
+    let mut use_context = false;
+    meta.parse_nested_meta(|meta| {
+        if meta.path.is_ident("function") {
+            function = Some(match meta.value()?.parse::<Expr>()? {
+                Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(lit), .. }) => lit.parse::<Expr>()?,
+                expr => expr,
+            });
+        } else if meta.path.is_ident("code") {
+            code = Some(meta.value()?.parse::<LitStr>()?);
+        } else if meta.path.is_ident("message") {
+            message = Some(meta.value()?.parse::<Expr>()?);
+        } else if meta.path.is_ident("async") {
+            is_async = true;
+        } else if meta.path.is_ident("use_context") {
+            use_context = true;
+        } else {
+            return Err(meta.error("expected `function`, `code`, `message`, `async` or `use_context`"));
+        }
+        Ok(())
+    })?;
+    let Some(function) = function else {
+        return Err(meta.error("missing `function = ...`"));
+    };
+    Ok(CustomRule { function, code, message, is_async, use_context })
+}
+
+/// Expression calling a custom function on the value, awaited for async
+/// functions. The optional code and message replace those of the error, which
+/// gets the field name as `field` param.
+fn custom_call(rule: &CustomRule, accessor: &proc_macro2::TokenStream, field: &str, ctx: bool) -> proc_macro2::TokenStream {
+    let function = &rule.function;
+    if rule.use_context && !ctx {
+        return syn::Error::new_spanned(function, "`use_context` requires `#[validate(context = \"...\")]` on the struct")
+            .to_compile_error();
+    }
+    let ctx_arg = rule.use_context.then(|| quote! { , __ctx });
+    let mut call = quote! { (#function)(&#accessor #ctx_arg) };
+    if rule.is_async {
+        call = quote! { ::validator::concurrency::timeout(__state.options().timeout, #call).await };
+    }
+    let code = rule.code.as_ref().map(|code| quote! { e.code = ::std::borrow::Cow::Borrowed(#code); });
+    let message = rule.message.as_ref().map(|message| quote! { e.message = ::std::borrow::Cow::from(#message); });
+    let mutability = (code.is_some() || message.is_some()).then(|| quote! { mut });
+    // A replaced message is a fresh template, rendered once against all params.
+    let with_field = match message {
+        Some(_) => quote! { e.with_param("field", #field).render() },
+        None => quote! { e.with_field(#field) },
+    };
+    quote! {
+        #call.map_err(|#mutability e: ::validator::prelude::ValidationError| {
+            #code
+            #message
+            #with_field
+        })
+    }
+}
+
+/// The fields to validate, depending on the kind of the derive input.
+enum Shape {
+    /// Fields of a struct, accessed through `self`
+    Struct(Vec<FieldSpec>),
+    /// Variants of an enum; only the fields of the active variant are validated
+    Enum(Vec<VariantSpec>),
+}
+
+/// Describes a variant of an enum:
+/// - pattern: `Self::Variant { a: __field_a, .. }`, binding every field by
+///   reference (also those without annotation, for cross-field rules)
+/// - key: variant name, used as path segment above the field errors
+/// - fields: specs of the annotated fields, accessed through their bindings
+struct VariantSpec {
+    pattern: proc_macro2::TokenStream,
+    key: String,
+    fields: Vec<FieldSpec>,
+}
+
+/// Collect the specs of all fields (of all variants, for enums) that carry a
+/// `#[validate(...)]` annotation. Unions are rejected.
+fn collect_shape(data: &Data) -> syn::Result<Shape> {
+    match data {
+        Data::Struct(ds) => Ok(Shape::Struct(collect_field_specs(&ds.fields, |member| quote! { self.#member })?)),
+        Data::Enum(de) => {
+            let variants = de
+                .variants
+                .iter()
+                .map(|variant| {
+                    let vident = &variant.ident;
+                    let members = variant.fields.members();
+                    let bindings = members.clone().map(|member| binding_ident(&member));
+                    let pattern = match &variant.fields {
+                        Fields::Named(_) => quote! { Self::#vident { #(#members: #bindings),* } },
+                        Fields::Unnamed(_) => quote! { Self::#vident ( #(#bindings),* ) },
+                        Fields::Unit => quote! { Self::#vident },
+                    };
+                    let fields = collect_field_specs(&variant.fields, |member| {
+                        let binding = binding_ident(member);
+                        quote! { (*#binding) }
+                    })?;
+                    Ok(VariantSpec { pattern, key: vident.to_string(), fields })
+                })
+                .collect::<syn::Result<_>>()?;
+            Ok(Shape::Enum(variants))
+        }
+        Data::Union(du) => Err(syn::Error::new_spanned(du.union_token, "`Validate` cannot be derived for unions")),
+    }
+}
+
+/// Name of the binding of an enum variant field in its match arm.
+fn binding_ident(member: &syn::Member) -> proc_macro2::Ident {
+    match member {
+        syn::Member::Named(ident) => format_ident!("__field_{}", ident),
+        syn::Member::Unnamed(index) => format_ident!("__field_{}", index.index),
+    }
 }
 
 /// Collect specs for all fields that carry a `#[validate(...)]` annotation.
 /// This looks only at the presence of the annotation on a field and prepares
 /// accessors/keys, delegating the parsing of the items inside `( ... )` to
-/// `find_validator_paths`.
-fn collect_field_specs(data: &Data, with_keys: bool) -> Vec<FieldSpec> {
+/// `find_validator_paths`. `access` turns a field (or a sibling named by a
+/// cross-field rule) into an accessor expression.
+fn collect_field_specs(
+    fields: &Fields,
+    access: impl Fn(&syn::Member) -> proc_macro2::TokenStream,
+) -> syn::Result<Vec<FieldSpec>> {
     let mut out = Vec::new();
-    match data {
-        Data::Struct(ds) => match &ds.fields {
-            Fields::Named(fields_named) => {
-                for field in fields_named.named.iter() {
-                    let vpaths = find_validator_paths(&field.attrs);
-                    if vpaths.is_empty() { continue; }
-                    let fname = field.ident.as_ref().expect("named field should have ident");
-                    let accessor = quote! { self.#fname };
-                    let key = if with_keys { Some(fname.to_string()) } else { None };
-                    out.push(FieldSpec { accessor, key, vpaths });
-                }
-            }
-            Fields::Unnamed(fields_unnamed) => {
-                for (idx, field) in fields_unnamed.unnamed.iter().enumerate() {
-                    let vpaths = find_validator_paths(&field.attrs);
-                    if vpaths.is_empty() { continue; }
-                    let index = syn::Index::from(idx);
-                    let accessor = quote! { self.#index };
-                    let key = if with_keys { Some(idx.to_string()) } else { None };
-                    out.push(FieldSpec { accessor, key, vpaths });
-                }
-            }
-            Fields::Unit => {}
-        },
-        _ => {}
+    let mut errors: Option<syn::Error> = None;
+    for (field, member) in fields.iter().zip(fields.members()) {
+        let mut vpaths = match find_validator_paths(&field.attrs) {
+            Ok(vpaths) => vpaths,
+            Err(err) => {
+                // Report the errors of all fields at once
+                match &mut errors {
+                    Some(errors) => errors.combine(err),
+                    None => errors = Some(err),
+                }
+                continue;
+            }
+        };
+        if vpaths.is_empty() { continue; }
+        resolve_siblings(&mut vpaths, &access, fields);
+        let key = match &member {
+            syn::Member::Named(ident) => ident.to_string(),
+            syn::Member::Unnamed(index) => index.index.to_string(),
+        };
+        out.push(FieldSpec { accessor: access(&member), ty: field.ty.clone(), key, vpaths });
+    }
+    match errors {
+        Some(errors) => Err(errors),
+        None => Ok(out),
+    }
+}
+
+/// Set the accessor (and type) of the sibling field of every cross-field rule.
+fn resolve_siblings(rules: &mut [Rule], access: &impl Fn(&syn::Member) -> proc_macro2::TokenStream, fields: &Fields) {
+    for rule in rules {
+        match rule {
+            Rule::CrossField(cross) => {
+                cross.sibling = access(&cross.member);
+                cross.sibling_ty = fields.iter().zip(fields.members()).find(|(_, member)| *member == cross.member).map(|(field, _)| field.ty.clone());
+            }
+            Rule::Each(inner, _) => resolve_siblings(inner, access, fields),
+            _ => {}
+        }
     }
-    out
 }
 
 /// Parse the inner items of a `#[validate(...)]` attribute into constructor
 /// expressions for validators. For unit-like items (e.g. `Email`) we emit
-/// `Email::default()`. For items with arguments (e.g. `Length(min = 3)`) we emit
-/// `Length::new(min = 3)`.
-fn find_validator_paths(attrs: &[Attribute]) -> Vec<proc_macro2::TokenStream> {
+/// `Email::default()`. For items with positional arguments (e.g. `MaxLength(10)`)
+/// we emit `MaxLength::new(10)`. Named arguments become builder calls on the
+/// validator: `Range(min = 1, max = 100)` emits `Range::default().min(1).max(100)`
+/// and `message = "..."` emits `.with_message("...")`. A string literal given as
+/// `other = "..."` or `field = "..."` names a sibling field and turns the item
+/// into a `Rule::CrossField`. The `nested` and
+/// `each(...)` keywords are kept as `Rule::Nested` and `Rule::Each`.
+fn find_validator_paths(attrs: &[Attribute]) -> syn::Result<Vec<Rule>> {
     let mut out = Vec::new();
     for attr in attrs {
         if !attr.path().is_ident("validate") { continue; }
-        if let Ok(list) = attr.meta.require_list() {
-            // Use syn's nested meta parser to handle items robustly (no hardcoded names)
-            let _ = list.parse_nested_meta(|meta| {
-                let p: Path = meta.path;
-                // If the item has parentheses, capture the inner tokens and emit `path(inner)`
-                if meta.input.peek(syn::token::Paren) {
-                    let content;
-                    let _paren = syn::parenthesized!(content in meta.input);
-                    let args_tokens: proc_macro2::TokenStream = content.parse()?;
-                    out.push(quote! { #p :: new ( #args_tokens ) });
-                } else {
-                    // Unit-like without args (e.g., Email)
-                    out.push(path_to_expr_tokens(&p));
+        // Use syn's nested meta parser to handle items robustly (no hardcoded names)
+        attr.parse_nested_meta(|meta| parse_rule(meta, &mut out))?;
+    }
+    check_duplicates(&out)?;
+    Ok(out)
+}
+
+/// Reject the same rule given twice for a value (e.g. `#[validate(Email, Email)]`
+/// or `MinLength(3)` twice). Validators are keyed on their arguments too, so
+/// `Range(min = 1)` next to `Range(max = 10)` is fine.
+fn check_duplicates(rules: &[Rule]) -> syn::Result<()> {
+    let mut seen = Vec::new();
+    for rule in rules {
+        let (key, tokens) = match rule {
+            Rule::Validator(validator, path) => (validator.to_string(), path.to_token_stream()),
+            Rule::CrossField(cross) => (format!("{} {}", cross.validator, cross.name), cross.path.to_token_stream()),
+            Rule::Custom(custom) => (custom.function.to_token_stream().to_string(), custom.function.to_token_stream()),
+            Rule::Nested(span) => ("nested".to_string(), quote_spanned! {*span=> nested }),
+            Rule::Each(inner, _) => {
+                check_duplicates(inner)?;
+                continue;
+            }
+        };
+        if seen.contains(&key) {
+            return Err(syn::Error::new_spanned(tokens, "duplicate rule; it is already given for this value"));
+        }
+        seen.push(key);
+    }
+    Ok(())
+}
+
+/// Lowercase single identifiers are keywords, validators are types.
+fn is_keyword(path: &Path) -> bool {
+    path.get_ident().is_some_and(|ident| ident.to_string().starts_with(|c: char| c.is_ascii_lowercase()))
+}
+
+/// Parse a single item of a `#[validate(...)]` annotation (or of an `each(...)`
+/// item) and push the resulting rule.
+fn parse_rule(meta: ParseNestedMeta, out: &mut Vec<Rule>) -> syn::Result<()> {
+    let has_args = meta.input.peek(syn::token::Paren);
+    if meta.input.peek(Token![=]) {
+        let name = meta.path.to_token_stream().to_string().replace(' ', "");
+        return Err(meta.error(format!("unexpected `=` after `{}`; pass arguments as `{}(...)`", name, name)));
+    }
+    let keyword = is_keyword(&meta.path).then(|| meta.path.to_token_stream().to_string());
+    match (keyword.as_deref(), has_args) {
+        // `nested` delegates to the field's own `Validate` impl
+        (Some("nested"), false) => {
+            out.push(Rule::Nested(meta.path.span()));
+            return Ok(());
+        }
+        // `custom(function = ...)` calls a plain function or closure
+        (Some("custom"), true) => {
+            out.push(Rule::Custom(Box::new(parse_custom_rule(&meta)?)));
+            return Ok(());
+        }
+        // `each(...)` applies the inner items to every item of a collection
+        (Some("each"), true) => {
+            let mut inner = Vec::new();
+            meta.parse_nested_meta(|meta| parse_rule(meta, &mut inner))?;
+            out.push(Rule::Each(inner, meta.path.span()));
+            return Ok(());
+        }
+        // `any(...)`, `all(...)` and `not(...)` combine validators
+        (Some(name @ ("any" | "all" | "not")), true) => {
+            out.push(parse_combinator(&meta, name)?);
+            return Ok(());
+        }
+        (Some("nested"), true) => return Err(meta.error("`nested` takes no arguments")),
+        (Some("custom"), false) => return Err(meta.error("expected `custom(function = ...)`")),
+        (Some("each"), false) => return Err(meta.error("expected rules for the items: `each(...)`")),
+        (Some(name @ ("any" | "all" | "not")), false) => return Err(meta.error(format!("expected validators: `{}(...)`", name))),
+        (Some(name @ ("schema" | "context")), _) => {
+            return Err(meta.error(format!("`{}` is only allowed in `#[validate(...)]` on the struct", name)));
+        }
+        (Some(name), _) => {
+            return Err(meta.error(format!(
+                "unknown keyword `{}`; expected `nested`, `each(...)`, `custom(...)`, `any(...)`, `all(...)`, `not(...)` or a validator type",
+                name
+            )));
+        }
+        (None, _) => {}
+    }
+    let p: Path = meta.path;
+    // If the item has parentheses, capture the inner tokens and emit `path(inner)`
+    if has_args {
+        let content;
+        let _paren = syn::parenthesized!(content in meta.input);
+        let args = Punctuated::<Expr, Token![,]>::parse_terminated(&content)?;
+        // Positional arguments go to `new(...)`, named ones become builder calls:
+        // `message = "..."` -> `.with_message("...")`, `min = 1` -> `.min(1)`
+        // `other = "field"` / `field = "field"` name the sibling of a cross-field validator
+        let mut ctor_args = Vec::new();
+        let mut setters = Vec::new();
+        let mut sibling = None;
+        for arg in args {
+            match arg {
+                Expr::Assign(assign) => {
+                    let Expr::Path(left) = &*assign.left else {
+                        return Err(syn::Error::new_spanned(&assign.left, "expected an argument name"));
+                    };
+                    let Some(name) = left.path.get_ident() else {
+                        return Err(syn::Error::new_spanned(&left.path, "expected an argument name"));
+                    };
+                    let value = assign.right;
+                    if let (true, Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(lit), .. })) =
+                        (name == "other" || name == "field", &*value)
+                    {
+                        sibling = Some((lit.parse::<syn::Member>()?, lit.value()));
+                    } else if name == "message" {
+                        setters.push(quote! { .with_message(#value) });
+                    } else {
+                        setters.push(quote! { .#name(#value) });
+                    }
                 }
-                Ok(())
-            });
+                arg => ctor_args.push(arg),
+            }
+        }
+        let ctor = if ctor_args.is_empty() && (!setters.is_empty() || sibling.is_some()) {
+            path_to_expr_tokens(&p)
+        } else {
+            quote! { #p :: new ( #(#ctor_args),* ) }
+        };
+        let validator = quote! { #ctor #(#setters)* };
+        match sibling {
+            Some((member, name)) => {
+                let sibling = quote! { self.#member };
+                out.push(Rule::CrossField(Box::new(CrossFieldRule { validator, path: p, member, name, sibling, sibling_ty: None })))
+            }
+            None => out.push(Rule::Validator(validator, p)),
         }
+    } else {
+        // Unit-like without args (e.g., Email)
+        out.push(Rule::Validator(path_to_expr_tokens(&p), p));
     }
-    out
+    Ok(())
+}
+
+/// Parse the validators of an `any(...)`, `all(...)` or `not(...)` item into a
+/// `Rule::Validator` constructing `AnyOf`, `AllOf` or `Not` (see
+/// `validator::validators::combinator`). `any` and `not` accept a
+/// `message = "..."` for the combined error.
+fn parse_combinator(meta: &ParseNestedMeta, name: &str) -> syn::Result<Rule> {
+    let mut inner = Vec::new();
+    let mut message = None;
+    meta.parse_nested_meta(|meta| {
+        if meta.path.is_ident("message") && meta.input.peek(Token![=]) && name != "all" {
+            message = Some(meta.value()?.parse::<Expr>()?);
+            return Ok(());
+        }
+        let span = meta.path.span();
+        let mut rules = Vec::new();
+        parse_rule(meta, &mut rules)?;
+        match rules.pop() {
+            Some(Rule::Validator(validator, _)) => inner.push(validator),
+            _ => return Err(syn::Error::new(span, format!("only validators are allowed in `{}(...)`", name))),
+        }
+        Ok(())
+    })?;
+    let combinator = match (name, inner.as_slice()) {
+        (_, []) => return Err(meta.error(format!("expected validators: `{}(...)`", name))),
+        ("not", [validator]) => quote! { ::validator::validators::Not::new(#validator) },
+        ("not", _) => return Err(meta.error("`not(...)` takes a single validator")),
+        ("any", _) => quote! { ::validator::validators::AnyOf::new((#(#inner,)*)) },
+        _ => quote! { ::validator::validators::AllOf::new((#(#inner,)*)) },
+    };
+    let message = message.map(|message| quote! { .with_message(#message) });
+    Ok(Rule::Validator(quote! { #combinator #message }, meta.path.clone()))
 }
 
 /// Helper used for unit-like validator items inside the annotation. Converts a
@@ -263,19 +1098,165 @@ fn path_to_expr_tokens(p: &Path) -> proc_macro2::TokenStream {
     quote! { #path_tokens :: default() }
 }
 
+/// Imports of the `validator::__private::SyncProbe` traits (or those of
+/// `ContextSyncProbe` with a context), spanned like their calls.
+fn sync_probe_imports(ctx: bool, span: Span) -> proc_macro2::TokenStream {
+    #[cfg(feature = "async")]
+    let async_only = if ctx {
+        quote_spanned! {span=> ViaAsyncContextOnly as _, ViaStaticAsyncContextOnly as _, }
+    } else {
+        quote_spanned! {span=> ViaAsyncOnly as _, ViaStaticAsyncOnly as _, }
+    };
+    #[cfg(not(feature = "async"))]
+    let async_only = quote! {};
+    let traits = if ctx {
+        quote_spanned! {span=> ViaContextValidator as _, #async_only ViaAnyContextValidator as _ }
+    } else {
+        quote_spanned! {span=> ViaValidator as _, #async_only ViaAnyValidator as _ }
+    };
+    quote_spanned! {span=>
+        #[allow(unused_imports)]
+        use ::validator::__private::{#traits};
+    }
+}
+
+/// Expression calling the validator `v` on the value; with a context through
+/// `ContextValidator`, which plain validators implement for any context.
+/// Validators implementing only the async traits pass, they are only run by
+/// `ValidateAsync` (see `validator::__private::SyncProbe`).
+/// The call is spanned at the validator in the attribute, so unsatisfied trait
+/// bounds point there. Without a context it is a method call, which goes
+/// through the `Validator` the user imported (e.g. with the prelude) when
+/// there is one, see `validator::__private::ValidateFallback`.
+fn validator_call(accessor: &proc_macro2::TokenStream, ctx: bool, span: Span) -> proc_macro2::TokenStream {
+    let imports = sync_probe_imports(ctx, span);
+    if ctx {
+        quote_spanned! {span=>
+            {
+                #imports
+                #[allow(unused_imports)]
+                use ::validator::prelude::ContextValidator as _;
+                (&&&&::validator::__private::ContextSyncProbe::of(&v, &(#accessor), __ctx)).sync_validator().validate_with(&(#accessor), __ctx)
+            }
+        }
+    } else {
+        quote_spanned! {span=>
+            {
+                #imports
+                #[allow(unused_imports)]
+                use ::validator::__private::ValidateFallback as _;
+                (&&&&::validator::__private::SyncProbe::of(&v, &(#accessor))).sync_validator().validate(&(#accessor))
+            }
+        }
+    }
+}
+
+/// Expression of the `nested` value to validate in `Validate`, standing in
+/// for values implementing only the async traits, which are only validated by
+/// `ValidateAsync` (see `validator::__private::NestedSyncProbe`).
+fn sync_nested(accessor: &proc_macro2::TokenStream, ctx: bool, span: Span) -> proc_macro2::TokenStream {
+    #[cfg(feature = "async")]
+    let async_only = if ctx {
+        quote_spanned! {span=> ViaValidateWithAsyncOnly as _, ViaStaticValidateWithAsyncOnly as _, }
+    } else {
+        quote_spanned! {span=> ViaValidateAsyncOnly as _, ViaStaticValidateAsyncOnly as _, }
+    };
+    #[cfg(not(feature = "async"))]
+    let async_only = quote! {};
+    let (via, probe) = if ctx {
+        (quote_spanned! {span=> ViaValidateWith }, quote_spanned! {span=> ::validator::__private::NestedSyncProbe::with(&(#accessor), __ctx) })
+    } else {
+        (quote_spanned! {span=> ViaValidate }, quote_spanned! {span=> ::validator::__private::NestedSyncProbe::of(&(#accessor)) })
+    };
+    quote_spanned! {span=>
+        {
+            #[allow(unused_imports)]
+            use ::validator::__private::{#via as _, #async_only ViaAnyNested as _};
+            (&&&&#probe).sync_nested()
+        }
+    }
+}
+
+/// Async counterpart to `validator_call`, preferring `StaticAsyncValidator`
+/// over `AsyncValidator` through `validator::__private::AsyncProbe`.
+#[cfg(feature = "async")]
+fn async_validator_call(accessor: &proc_macro2::TokenStream, ctx: bool, span: Span) -> proc_macro2::TokenStream {
+    let call = if ctx {
+        quote_spanned! {span=>
+            {
+                #[allow(unused_imports)]
+                use ::validator::__private::{ViaAsyncContextValidator as _, ViaStaticAsyncContextValidator as _};
+                (&::validator::__private::AsyncContextProbe(&v, &(#accessor), __ctx)).validate_with_probe()
+            }
+        }
+    } else {
+        quote_spanned! {span=>
+            {
+                #[allow(unused_imports)]
+                use ::validator::__private::{ViaAsyncValidator as _, ViaStaticAsyncValidator as _};
+                (&::validator::__private::AsyncProbe(&v, &(#accessor))).validate_probe()
+            }
+        }
+    };
+    quote! { ::validator::concurrency::timeout(__state.options().timeout, #call).await }
+}
+
+/// Expression calling a cross-field validator with the value and its sibling.
+/// Errors get the sibling name as `other` param and the field name as `field`
+/// param. Cross-field validators are synchronous, also in `ValidateAsync`.
+fn cross_field_call(cross: &CrossFieldRule, accessor: &proc_macro2::TokenStream, field: &str) -> proc_macro2::TokenStream {
+    let CrossFieldRule { validator, name, sibling, .. } = cross;
+    quote! {
+        ::validator::prelude::CrossFieldValidator::validate(&#validator, &(#accessor), &#sibling)
+            .map_err(|e| e.with_rendered_param("other", #name).with_field(#field))
+    }
+}
+
 /// Emit short-circuiting validate statements for each validator item found in
-/// the field's `#[validate(...)]` annotation. On first error, returns `Err`.
+/// the field's `#[validate(...)]` annotation. On first error, returns `Err` via `?`.
+/// Validator errors get the field name as `field` param (see `ValidationError::with_field`).
 fn build_validate_for_accessor(
     accessor: proc_macro2::TokenStream,
-    vpaths: &[proc_macro2::TokenStream],
+    field: &str,
+    vpaths: &[Rule],
+    ctx: bool,
 ) -> Vec<proc_macro2::TokenStream> {
     let mut stmts = Vec::new();
-    for vpath in vpaths {
-        let stmt = quote! {
-            {
-                let v = #vpath;
-                if let Err(e) = v.validate(&#accessor) {
-                    return Err(e);
+    for rule in vpaths {
+        let stmt = match rule {
+            Rule::Validator(vpath, path) => {
+                let call = validator_call(&accessor, ctx, path.span());
+                quote! {
+                    {
+                        let v = #vpath;
+                        #call.map_err(|e| e.with_field(#field))?;
+                    }
+                }
+            }
+            Rule::Custom(rule) if rule.is_async => continue,
+            Rule::Custom(rule) => {
+                let call = custom_call(rule, &accessor, field, ctx);
+                quote! { #call?; }
+            }
+            Rule::CrossField(cross) => {
+                let call = cross_field_call(cross, &accessor, field);
+                quote! { #call?; }
+            }
+            Rule::Nested(span) => {
+                let nested = sync_nested(&accessor, ctx, *span);
+                if ctx {
+                    quote_spanned! {*span=> ::validator::validate::ValidateWith::validate_with_in(#nested, __ctx, __state)?; }
+                } else {
+                    quote_spanned! {*span=> ::validator::validate::Validate::validate_in(#nested, __state)?; }
+                }
+            }
+            Rule::Each(rules, span) => {
+                let inner = build_validate_for_accessor(quote! { (*__item) }, field, rules, ctx);
+                let items = quote_spanned! {*span=> ::validator::collection::Each::items(&(#accessor)) };
+                quote! {
+                    for (_, __item) in #items {
+                        #(#inner)*
+                    }
                 }
             }
         };
@@ -285,40 +1266,137 @@ fn build_validate_for_accessor(
 }
 
 /// Emit error-collecting validate statements for each validator item found in
-/// the field's `#[validate(...)]` annotation. Errors are pushed under the
-/// provided key.
+/// the field's `#[validate(...)]` annotation. Errors are pushed to the
+/// `ValidationErrors` of the given `each(...)` depth; errors of nested values
+/// are attached below it and errors of `each(...)` items below their index or key.
+/// Every rule runs only while the state allows it (`max_errors`,
+/// `stop_on_first_error_per_field`) and counts the errors it reports.
 fn build_complete_validate_for_accessor(
     accessor: proc_macro2::TokenStream,
-    key: String,
-    vpaths: &[proc_macro2::TokenStream],
+    field: &str,
+    depth: usize,
+    vpaths: &[Rule],
+    ctx: bool,
 ) -> Vec<proc_macro2::TokenStream> {
+    let target = errors_ident(depth);
     let mut stmts = Vec::new();
-    for vpath in vpaths {
-        let stmt = quote! {
-            {
-                let v = #vpath;
-                if let Err(e) = v.validate(&#accessor) {
-                    __errors.entry(#key.to_string()).or_insert_with(::std::vec::Vec::new).push(e);
+    for rule in vpaths {
+        let stmt = match rule {
+            Rule::Validator(vpath, path) => {
+                let call = validator_call(&accessor, ctx, path.span());
+                quote! {
+                    {
+                        let v = #vpath;
+                        if let Err(e) = #call {
+                            #target.add_error(e.with_field(#field));
+                            __state.record_error();
+                        }
+                    }
+                }
+            }
+            Rule::Custom(rule) if rule.is_async => continue,
+            Rule::Custom(rule) => {
+                let call = custom_call(rule, &accessor, field, ctx);
+                quote! {
+                    if let Err(e) = #call {
+                        #target.add_error(e);
+                        __state.record_error();
+                    }
+                }
+            }
+            Rule::CrossField(cross) => {
+                let call = cross_field_call(cross, &accessor, field);
+                quote! {
+                    if let Err(e) = #call {
+                        #target.add_error(e);
+                        __state.record_error();
+                    }
+                }
+            }
+            Rule::Nested(span) => {
+                let nested = sync_nested(&accessor, ctx, *span);
+                let call = if ctx {
+                    quote_spanned! {*span=> ::validator::validate::ValidateWith::complete_validate_with_in(#nested, __ctx, __state) }
+                } else {
+                    quote_spanned! {*span=> ::validator::validate::Validate::complete_validate_in(#nested, __state) }
+                };
+                quote! {
+                    if let Err(nested) = #call {
+                        #target.merge(nested);
+                    }
+                }
+            }
+            Rule::Each(rules, span) => {
+                let item_target = errors_ident(depth + 1);
+                let inner = build_complete_validate_for_accessor(quote! { (*__item) }, field, depth + 1, rules, ctx);
+                let items = quote_spanned! {*span=> ::validator::collection::Each::items(&(#accessor)) };
+                quote! {
+                    for (__segment, __item) in #items {
+                        let mut #item_target = ::validator::prelude::ValidationErrors::new();
+                        #(#inner)*
+                        #target.nest(__segment, #item_target);
+                    }
                 }
             }
         };
-        stmts.push(stmt);
+        stmts.push(quote! {
+            if __state.runs_rule(&#target) {
+                #stmt
+            }
+        });
     }
     stmts
 }
 
 /// Async counterpart to `build_validate_for_accessor`
+#[cfg(feature = "async")]
 fn build_async_validate_for_accessor(
     accessor: proc_macro2::TokenStream,
-    vpaths: &[proc_macro2::TokenStream],
+    field: &str,
+    vpaths: &[Rule],
+    ctx: bool,
 ) -> Vec<proc_macro2::TokenStream> {
     let mut stmts = Vec::new();
-    for vpath in vpaths {
-        let stmt = quote! {
-            {
-                let v = #vpath;
-                if let Err(e) = ::validator::prelude::AsyncValidator::validate_async(&v, &#accessor).await {
-                    return Err(e);
+    for rule in vpaths {
+        let stmt = match rule {
+            Rule::Validator(vpath, path) => {
+                let call = async_validator_call(&accessor, ctx, path.span());
+                quote! {
+                    {
+                        let v = #vpath;
+                        #call.map_err(|e| e.with_field(#field))?;
+                    }
+                }
+            }
+            Rule::Custom(rule) => {
+                let call = custom_call(rule, &accessor, field, ctx);
+                quote! { #call?; }
+            }
+            Rule::CrossField(cross) => {
+                let call = cross_field_call(cross, &accessor, field);
+                quote! { #call?; }
+            }
+            Rule::Nested(span) if ctx => quote_spanned! {*span=>
+                {
+                    #[allow(unused_imports)]
+                    use ::validator::__private::{ViaStaticValidateWithAsync as _, ViaValidateWithAsync as _};
+                    ::std::boxed::Box::pin((&::validator::__private::NestedContextProbe(&(#accessor), __ctx)).validate_nested(__state)).await?;
+                }
+            },
+            Rule::Nested(span) => quote_spanned! {*span=>
+                {
+                    #[allow(unused_imports)]
+                    use ::validator::__private::{ViaStaticValidateAsync as _, ViaValidateAsync as _};
+                    ::std::boxed::Box::pin((&::validator::__private::NestedProbe(&(#accessor))).validate_nested(__state)).await?;
+                }
+            },
+            Rule::Each(rules, span) => {
+                let inner = build_async_validate_for_accessor(quote! { (*__item) }, field, rules, ctx);
+                let items = quote_spanned! {*span=> ::validator::collection::Each::items(&(#accessor)) };
+                quote! {
+                    for (_, __item) in #items {
+                        #(#inner)*
+                    }
                 }
             }
         };
@@ -328,22 +1406,363 @@ fn build_async_validate_for_accessor(
 }
 
 /// Async counterpart to `build_complete_validate_for_accessor`
+#[cfg(feature = "async")]
 fn build_async_complete_validate_for_accessor(
     accessor: proc_macro2::TokenStream,
-    key: String,
-    vpaths: &[proc_macro2::TokenStream],
+    field: &str,
+    depth: usize,
+    vpaths: &[Rule],
+    ctx: bool,
 ) -> Vec<proc_macro2::TokenStream> {
+    let target = errors_ident(depth);
     let mut stmts = Vec::new();
-    for vpath in vpaths {
-        let stmt = quote! {
-            {
-                let v = #vpath;
-                if let Err(e) = ::validator::prelude::AsyncValidator::validate_async(&v, &#accessor).await {
-                    __errors.entry(#key.to_string()).or_insert_with(::std::vec::Vec::new).push(e);
+    for rule in vpaths {
+        let stmt = match rule {
+            Rule::Validator(vpath, path) => {
+                let call = async_validator_call(&accessor, ctx, path.span());
+                quote! {
+                    {
+                        let v = #vpath;
+                        if let Err(e) = #call {
+                            #target.add_error(e.with_field(#field));
+                            __state.record_error();
+                        }
+                    }
+                }
+            }
+            Rule::Custom(rule) => {
+                let call = custom_call(rule, &accessor, field, ctx);
+                quote! {
+                    if let Err(e) = #call {
+                        #target.add_error(e);
+                        __state.record_error();
+                    }
+                }
+            }
+            Rule::CrossField(cross) => {
+                let call = cross_field_call(cross, &accessor, field);
+                quote! {
+                    if let Err(e) = #call {
+                        #target.add_error(e);
+                        __state.record_error();
+                    }
+                }
+            }
+            Rule::Nested(span) if ctx => quote_spanned! {*span=>
+                {
+                    #[allow(unused_imports)]
+                    use ::validator::__private::{ViaStaticValidateWithAsync as _, ViaValidateWithAsync as _};
+                    if let Err(nested) = ::std::boxed::Box::pin((&::validator::__private::NestedContextProbe(&(#accessor), __ctx)).complete_validate_nested(__state)).await {
+                        #target.merge(nested);
+                    }
+                }
+            },
+            Rule::Nested(span) => quote_spanned! {*span=>
+                {
+                    #[allow(unused_imports)]
+                    use ::validator::__private::{ViaStaticValidateAsync as _, ViaValidateAsync as _};
+                    if let Err(nested) = ::std::boxed::Box::pin((&::validator::__private::NestedProbe(&(#accessor))).complete_validate_nested(__state)).await {
+                        #target.merge(nested);
+                    }
+                }
+            },
+            Rule::Each(rules, span) => {
+                let item_target = errors_ident(depth + 1);
+                let inner = build_async_complete_validate_for_accessor(quote! { (*__item) }, field, depth + 1, rules, ctx);
+                let items = quote_spanned! {*span=> ::validator::collection::Each::items(&(#accessor)) };
+                quote! {
+                    for (__segment, __item) in #items {
+                        let mut #item_target = ::validator::prelude::ValidationErrors::new();
+                        #(#inner)*
+                        #target.nest(__segment, #item_target);
+                    }
                 }
             }
         };
-        stmts.push(stmt);
+        stmts.push(quote! {
+            if __state.runs_rule(&#target) {
+                #stmt
+            }
+        });
     }
     stmts
-}
\ No newline at end of file
+}
+
+/// Expressions building the `FieldRules` of every annotated field (keyed like
+/// its errors, `Variant.field` for enums) and of the struct-level `schema(...)`
+/// hooks (`__all__`).
+fn build_field_rules(shape: &Shape, schemas: &[SchemaSpec], ctx: Option<&syn::Type>) -> Vec<proc_macro2::TokenStream> {
+    let fields: Vec<(String, &FieldSpec)> = match shape {
+        Shape::Struct(fields) => fields.iter().map(|spec| (spec.key.clone(), spec)).collect(),
+        Shape::Enum(variants) => variants
+            .iter()
+            .flat_map(|variant| variant.fields.iter().map(move |spec| (format!("{}.{}", variant.key, spec.key), spec)))
+            .collect(),
+    };
+    let mut out: Vec<_> = fields
+        .into_iter()
+        .map(|(key, spec)| {
+            let rules = spec.vpaths.iter().map(|rule| rule_descriptor(rule, &spec.ty, ctx));
+            quote! { ::validator::rules::FieldRules { field: ::std::borrow::Cow::Borrowed(#key), rules: ::std::vec![#(#rules),*] } }
+        })
+        .collect();
+    if !schemas.is_empty() {
+        let rules = schemas.iter().map(|schema| {
+            let function = schema.function.to_token_stream().to_string().replace(' ', "");
+            quote! { ::validator::rules::RuleDescriptor::new("schema", "").with_param("function", #function) }
+        });
+        out.push(quote! {
+            ::validator::rules::FieldRules {
+                field: ::std::borrow::Cow::Borrowed(::validator::prelude::ValidationErrors::STRUCT_KEY),
+                rules: ::std::vec![#(#rules),*],
+            }
+        });
+    }
+    out
+}
+
+/// Name of the function of a custom rule as written in the annotation,
+/// `closure` for closures.
+fn custom_function_name(custom: &CustomRule) -> String {
+    match &custom.function {
+        Expr::Closure(_) => "closure".to_string(),
+        function => function.to_token_stream().to_string().replace(' ', ""),
+    }
+}
+
+/// Expression building the validator `validator` for values of type `ty`
+/// (with the context `ctx`), pinned through `validator::__private::SyncProbe`.
+/// Spanned at the validator in the annotation like `validator_call`.
+fn validator_for(validator: &proc_macro2::TokenStream, ty: &syn::Type, ctx: Option<&syn::Type>, span: Span) -> proc_macro2::TokenStream {
+    let imports = sync_probe_imports(ctx.is_some(), span);
+    let probe = match ctx {
+        Some(ctx) => quote_spanned! {span=>
+            ::validator::__private::ContextSyncProbe(&v, ::core::marker::PhantomData::<fn(&#ty, &#ctx)>)
+        },
+        None => quote_spanned! {span=>
+            ::validator::__private::SyncProbe(&v, ::core::marker::PhantomData::<fn(&#ty)>)
+        },
+    };
+    quote_spanned! {span=>
+        {
+            #imports
+            let v = #validator;
+            (&&&&#probe).sync_validator();
+            v
+        }
+    }
+}
+
+/// Expression building the `RuleDescriptor` of a rule for a value of type
+/// `ty`. Validators describe themselves when they implement `Describe` and are
+/// named after the path in the annotation otherwise.
+fn rule_descriptor(rule: &Rule, ty: &syn::Type, ctx: Option<&syn::Type>) -> proc_macro2::TokenStream {
+    let describe = |validator: &proc_macro2::TokenStream, path: &Path| {
+        let name = path.segments.last().map(|segment| segment.ident.to_string()).unwrap_or_default();
+        quote! {
+            {
+                #[allow(unused_imports)]
+                use ::validator::rules::__private::{ViaDescribe as _, ViaName as _};
+                let v = #validator;
+                (&::validator::rules::__private::DescribeProbe(&v, #name)).describe_rule()
+            }
+        }
+    };
+    match rule {
+        Rule::Validator(validator, path) => describe(&validator_for(validator, ty, ctx, path.span()), path),
+        Rule::CrossField(cross) => {
+            let validator = match &cross.sibling_ty {
+                Some(sibling_ty) => {
+                    let validator = &cross.validator;
+                    quote_spanned! {cross.path.span()=>
+                        ::validator::__private::cross_field_validator_for(#validator, ::core::marker::PhantomData::<fn(&#ty, &#sibling_ty)>)
+                    }
+                }
+                None => cross.validator.clone(),
+            };
+            let descriptor = describe(&validator, &cross.path);
+            let other = &cross.name;
+            quote! { #descriptor.with_param("other", #other) }
+        }
+        Rule::Custom(custom) => {
+            let function = custom_function_name(custom);
+            let code = custom.code.as_ref().map(LitStr::value).unwrap_or_default();
+            let message = match &custom.message {
+                Some(Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(message), .. })) => quote! { .with_message(Some(#message)) },
+                _ => quote! {},
+            };
+            let is_async = custom.is_async.then(|| quote! { .with_param("async", "true") });
+            quote! { ::validator::rules::RuleDescriptor::new("custom", #code).with_param("function", #function) #is_async #message }
+        }
+        Rule::Nested(_) => quote! { ::validator::rules::RuleDescriptor::new("nested", "") },
+        Rule::Each(inner, _) => {
+            let item: syn::Type = syn::parse_quote! { <#ty as ::validator::collection::Each>::Item };
+            let inner = inner.iter().map(|rule| rule_descriptor(rule, &item, ctx));
+            quote! {
+                ::validator::rules::RuleDescriptor { rules: ::std::vec![#(#inner),*], ..::validator::rules::RuleDescriptor::new("each", "") }
+            }
+        }
+    }
+}
+
+/// Expression building the JSON Schema of the derive input: the schema of the
+/// fields for structs, `oneOf` the variants (externally tagged, as serde
+/// represents them) for enums.
+#[cfg(feature = "json_schema")]
+fn data_json_schema(data: &Data, ctx: Option<&syn::Type>) -> proc_macro2::TokenStream {
+    match data {
+        Data::Struct(ds) => fields_json_schema(&ds.fields, ctx),
+        Data::Enum(de) => {
+            let variants = de.variants.iter().map(|variant| {
+                let name = variant.ident.to_string();
+                if let Fields::Unit = variant.fields {
+                    return quote! { ::validator::json_schema::__private::serde_json::json!({ "const": #name }) };
+                }
+                let fields = fields_json_schema(&variant.fields, ctx);
+                quote! {
+                    ::validator::json_schema::__private::object(
+                        ::std::iter::once((#name.to_string(), #fields)).collect(),
+                        ::std::vec![#name],
+                    )
+                }
+            });
+            quote! { ::validator::json_schema::__private::one_of(::std::vec![#(#variants),*]) }
+        }
+        // Rejected by `collect_shape`
+        Data::Union(_) => quote! { ::validator::json_schema::__private::serde_json::Value::Null },
+    }
+}
+
+/// Expression building the JSON Schema of `fields`: an object with the
+/// annotated fields as properties (required unless they are an `Option`), the
+/// schema of the field for newtypes and `prefixItems` for other tuples.
+#[cfg(feature = "json_schema")]
+fn fields_json_schema(fields: &Fields, ctx: Option<&syn::Type>) -> proc_macro2::TokenStream {
+    // The rules were checked by `collect_shape` already
+    let field_schemas = fields.iter().map(|field| {
+        let rules = find_validator_paths(&field.attrs).unwrap_or_default();
+        (!rules.is_empty(), field_json_schema(&field.ty, &rules, ctx))
+    });
+    match fields {
+        Fields::Named(_) => {
+            let mut properties = Vec::new();
+            let mut required = Vec::new();
+            for (field, (annotated, schema)) in fields.iter().zip(field_schemas) {
+                if !annotated {
+                    continue;
+                }
+                let name = field.ident.as_ref().map(ToString::to_string).unwrap_or_default();
+                if !is_option(&field.ty) {
+                    required.push(name.clone());
+                }
+                properties.push(quote! { __properties.insert(#name.to_string(), #schema); });
+            }
+            quote! {
+                {
+                    let mut __properties = ::validator::json_schema::__private::serde_json::Map::new();
+                    #(#properties)*
+                    ::validator::json_schema::__private::object(__properties, ::std::vec![#(#required),*])
+                }
+            }
+        }
+        Fields::Unnamed(unnamed) if unnamed.unnamed.len() == 1 => field_schemas.map(|(_, schema)| schema).collect(),
+        Fields::Unnamed(_) => {
+            let items = field_schemas.map(|(_, schema)| schema);
+            quote! { ::validator::json_schema::__private::tuple(::std::vec![#(#items),*]) }
+        }
+        Fields::Unit => quote! { ::validator::json_schema::__private::serde_json::json!({ "type": "null" }) },
+    }
+}
+
+/// Expression building the JSON Schema of a field of type `ty`: the schema of
+/// the type (when it implements `JsonSchema`) with the keywords of the rules.
+#[cfg(feature = "json_schema")]
+fn field_json_schema(ty: &syn::Type, rules: &[Rule], ctx: Option<&syn::Type>) -> proc_macro2::TokenStream {
+    let rules = rules_json_schema(rules, ty, ctx);
+    quote! {
+        {
+            #[allow(unused_imports)]
+            use ::validator::json_schema::__private::{ViaAnyType as _, ViaJsonSchema as _};
+            let mut __type_schema = (&::validator::json_schema::__private::TypeProbe::<#ty>::new()).type_schema();
+            let __schema = &mut __type_schema;
+            #(#rules)*
+            ::validator::json_schema::__private::serde_json::Value::Object(__type_schema)
+        }
+    }
+}
+
+/// Statements adding the keywords of `rules` for a value of type `ty` to
+/// `__schema`. `each(...)` rules describe the items of collections and the
+/// value of an `Option` itself. Validators without a `JsonSchemaRule` impl,
+/// custom functions and cross-field rules are listed in the `x-validator`
+/// annotation; `nested` adds nothing beyond the schema of the type.
+#[cfg(feature = "json_schema")]
+fn rules_json_schema(rules: &[Rule], ty: &syn::Type, ctx: Option<&syn::Type>) -> Vec<proc_macro2::TokenStream> {
+    let option_item = option_item(ty);
+    rules
+        .iter()
+        .filter_map(|rule| match rule {
+            Rule::Validator(validator, path) => {
+                let name = path.segments.last().map(|segment| segment.ident.to_string()).unwrap_or_default();
+                let validator = validator_for(validator, ty, ctx, path.span());
+                Some(quote! {
+                    {
+                        #[allow(unused_imports)]
+                        use ::validator::json_schema::__private::{ViaAnyRule as _, ViaJsonSchemaRule as _};
+                        let v = #validator;
+                        (&::validator::json_schema::__private::RuleProbe(&v, #name)).rule_schema(__schema);
+                    }
+                })
+            }
+            Rule::Each(inner, _) if option_item.is_some() => {
+                let inner = rules_json_schema(inner, option_item.unwrap_or(ty), ctx);
+                Some(quote! { #(#inner)* })
+            }
+            Rule::Each(inner, _) => {
+                let item: syn::Type = syn::parse_quote! { <#ty as ::validator::collection::Each>::Item };
+                let inner = rules_json_schema(inner, &item, ctx);
+                Some(quote! {
+                    ::validator::json_schema::__private::with_items(__schema, |__schema| { #(#inner)* });
+                })
+            }
+            Rule::Custom(custom) => {
+                let function = custom_function_name(custom);
+                Some(quote! {
+                    ::validator::json_schema::__private::annotate(
+                        __schema,
+                        ::validator::json_schema::__private::serde_json::json!({ "name": "custom", "function": #function }),
+                    );
+                })
+            }
+            Rule::CrossField(cross) => {
+                let name = cross.path.segments.last().map(|segment| segment.ident.to_string()).unwrap_or_default();
+                let other = &cross.name;
+                Some(quote! {
+                    ::validator::json_schema::__private::annotate(
+                        __schema,
+                        ::validator::json_schema::__private::serde_json::json!({ "name": #name, "other": #other }),
+                    );
+                })
+            }
+            Rule::Nested(_) => None,
+        })
+        .collect()
+}
+
+/// `true` for `Option<...>` field types, which are not required.
+#[cfg(feature = "json_schema")]
+fn is_option(ty: &syn::Type) -> bool {
+    matches!(ty, syn::Type::Path(path) if path.path.segments.last().is_some_and(|segment| segment.ident == "Option"))
+}
+
+/// The `T` of an `Option<T>` type.
+#[cfg(feature = "json_schema")]
+fn option_item(ty: &syn::Type) -> Option<&syn::Type> {
+    let syn::Type::Path(path) = ty else { return None };
+    let segment = path.path.segments.last().filter(|segment| segment.ident == "Option")?;
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else { return None };
+    match args.args.first()? {
+        syn::GenericArgument::Type(item) => Some(item),
+        _ => None,
+    }
+}
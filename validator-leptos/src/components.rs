@@ -0,0 +1,61 @@
+use crate::form::FormField;
+use leptos::prelude::*;
+use validator::prelude::*;
+
+/// `<input>` bound to a text field of a form: updates the field on input,
+/// marks it as touched on blur and sets `aria-invalid` while it shows errors.
+#[component]
+pub fn TextInput<T: Send + Sync + 'static>(
+    field: FormField<T, String>,
+    #[prop(default = "text")] input_type: &'static str,
+    #[prop(optional, into)] placeholder: String,
+    #[prop(default = "block border rounded px-2 py-1 w-full")] class: &'static str,
+) -> impl IntoView {
+    view! {
+        <input
+            id=field.name()
+            name=field.name()
+            type=input_type
+            placeholder=placeholder
+            class=class
+            prop:value=move || field.get()
+            aria-invalid=move || field.is_invalid().get().to_string()
+            on:input:target=move |ev| field.set(ev.target().value())
+            on:blur=move |_| field.blur()
+        />
+    }
+}
+
+/// Messages of the errors of one field, e.g. `FormField::errors`; renders
+/// nothing without errors.
+#[component]
+pub fn FieldErrors(#[prop(into)] errors: Signal<Vec<ValidationError>>) -> impl IntoView {
+    view! {
+        <Show when=move || errors.with(|errors| !errors.is_empty())>
+            <ul role="alert" class="text-red-600 text-sm mt-1">
+                {move || errors.get().into_iter().map(|error| view! { <li>{error.message.into_owned()}</li> }).collect_view()}
+            </ul>
+        </Show>
+    }
+}
+
+/// All errors of a form as `path: message` list, e.g. `Form::errors` after a
+/// submit attempt; renders nothing without errors.
+#[component]
+pub fn ErrorList(#[prop(into)] errors: Signal<ValidationErrors>) -> impl IntoView {
+    let items = move || {
+        errors.with(|errors| {
+            errors
+                .iter()
+                .flat_map(|(path, errors)| errors.iter().map(move |error| format!("{}: {}", path, error.message)))
+                .collect::<Vec<_>>()
+        })
+    };
+    view! {
+        <Show when=move || errors.with(|errors| !errors.is_empty())>
+            <ul role="alert" class="text-red-600 text-sm space-y-1">
+                {move || items().into_iter().map(|item| view! { <li>{item}</li> }).collect_view()}
+            </ul>
+        </Show>
+    }
+}
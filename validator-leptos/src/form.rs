@@ -0,0 +1,274 @@
+use leptos::prelude::*;
+use std::collections::HashSet;
+use validator::prelude::*;
+
+/// When the errors of a field are shown. Errors are computed on every change
+/// either way; after a submit attempt all fields show their errors.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ValidateMode {
+    /// Once the value of the field was changed.
+    OnChange,
+    /// Once the field lost focus.
+    #[default]
+    OnBlur,
+    /// Once the form was submitted.
+    OnSubmit,
+}
+
+/// Reactive state of a form editing a `T`, see [`use_form`].
+///
+/// The errors are a memo over `complete_validate` of the current value, so
+/// they are keyed like the errors of `T` (`email`, `address.street`,
+/// `tags[0]`, `__all__`).
+pub struct Form<T: Send + Sync + 'static> {
+    value: RwSignal<T>,
+    initial: StoredValue<T>,
+    errors: Memo<ValidationErrors>,
+    touched: RwSignal<HashSet<&'static str>>,
+    changed: RwSignal<HashSet<&'static str>>,
+    submitted: RwSignal<bool>,
+    mode: ValidateMode,
+}
+
+impl<T: Send + Sync + 'static> Clone for Form<T> {
+    fn clone(&self) -> Self { *self }
+}
+
+impl<T: Send + Sync + 'static> Copy for Form<T> {}
+
+/// Form state for a `T` starting at `T::default()`, showing errors on blur.
+///
+/// Example
+/// ```no_run
+/// use leptos::prelude::*;
+/// use validator::validators::Email;
+/// use validator_leptos::{FieldErrors, TextInput, ValidateMode, form_field, use_form};
+///
+/// #[derive(Clone, Default, validator::Validate)]
+/// struct Newsletter {
+///     #[validate(Email)]
+///     email: String,
+/// }
+///
+/// #[component]
+/// fn Subscribe() -> impl IntoView {
+///     let form = use_form::<Newsletter>().mode(ValidateMode::OnChange);
+///     let email = form_field!(form, email);
+///     view! {
+///         <form on:submit=form.on_submit(|newsletter| leptos::logging::log!("{}", newsletter.email))>
+///             <TextInput field=email input_type="email" />
+///             <FieldErrors errors=email.errors() />
+///             <button type="submit" disabled=move || !form.is_valid().get()>"Subscribe"</button>
+///         </form>
+///     }
+/// }
+/// ```
+pub fn use_form<T: Validate + Default + Clone + Send + Sync + 'static>() -> Form<T> { use_form_with(T::default()) }
+
+/// Form state for a `T` starting at `initial`, e.g. a record loaded for
+/// editing. Fields are dirty while they differ from `initial`.
+pub fn use_form_with<T: Validate + Clone + Send + Sync + 'static>(initial: T) -> Form<T> {
+    let value = RwSignal::new(initial.clone());
+    Form {
+        value,
+        initial: StoredValue::new(initial),
+        errors: Memo::new(move |_| value.with(|value| value.complete_validate().err().unwrap_or_default())),
+        touched: RwSignal::new(HashSet::new()),
+        changed: RwSignal::new(HashSet::new()),
+        submitted: RwSignal::new(false),
+        mode: ValidateMode::default(),
+    }
+}
+
+impl<T: Send + Sync + 'static> Form<T> {
+    /// Show the errors of fields according to `mode`.
+    pub fn mode(mut self, mode: ValidateMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// The field `name` of the form, read and written through `get` and
+    /// `get_mut`. `name` is the key of its errors; see [`form_field!`] for
+    /// struct fields.
+    pub fn field<V>(&self, name: &'static str, get: fn(&T) -> &V, get_mut: fn(&mut T) -> &mut V) -> FormField<T, V> {
+        FormField { form: *self, name, get, get_mut }
+    }
+
+    /// The current value.
+    pub fn value(&self) -> ReadSignal<T> { self.value.read_only() }
+
+    /// All errors of the current value, shown or not.
+    pub fn errors(&self) -> Memo<ValidationErrors> { self.errors }
+
+    /// `true` while the current value has no errors.
+    pub fn is_valid(&self) -> Signal<bool> {
+        let errors = self.errors;
+        Signal::derive(move || errors.with(ValidationErrors::is_empty))
+    }
+
+    /// `true` after a submit attempt, until [`reset`](Self::reset).
+    pub fn is_submitted(&self) -> Signal<bool> { self.submitted.into() }
+
+    /// `true` while the value differs from the initial one.
+    pub fn is_dirty(&self) -> Signal<bool>
+    where
+        T: PartialEq,
+    {
+        let (value, initial) = (self.value, self.initial);
+        Signal::derive(move || value.with(|value| initial.with_value(|initial| value != initial)))
+    }
+
+    /// Mark the form as submitted, showing the errors of all fields, and
+    /// return the value when it is valid.
+    pub fn submit(&self) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.submitted.set(true);
+        if self.errors.with_untracked(ValidationErrors::is_empty) { Some(self.value.get_untracked()) } else { None }
+    }
+
+    /// `on:submit` handler preventing the default submission and calling
+    /// `f` with the value when it is valid (see [`submit`](Self::submit)).
+    pub fn on_submit(self, f: impl Fn(T) + 'static) -> impl FnMut(leptos::ev::SubmitEvent) + 'static
+    where
+        T: Clone,
+    {
+        move |ev| {
+            ev.prevent_default();
+            if let Some(value) = self.submit() {
+                f(value);
+            }
+        }
+    }
+
+    /// Restore the initial value and forget touched, changed and submitted
+    /// state.
+    pub fn reset(&self)
+    where
+        T: Clone,
+    {
+        self.value.set(self.initial.get_value());
+        self.touched.update(HashSet::clear);
+        self.changed.update(HashSet::clear);
+        self.submitted.set(false);
+    }
+}
+
+/// One field of a [`Form`], with signals for its value, state and errors.
+pub struct FormField<T: Send + Sync + 'static, V> {
+    form: Form<T>,
+    name: &'static str,
+    get: fn(&T) -> &V,
+    get_mut: fn(&mut T) -> &mut V,
+}
+
+impl<T: Send + Sync + 'static, V> Clone for FormField<T, V> {
+    fn clone(&self) -> Self { *self }
+}
+
+impl<T: Send + Sync + 'static, V> Copy for FormField<T, V> {}
+
+impl<T: Send + Sync + 'static, V: 'static> FormField<T, V> {
+    /// The name of the field, the key of its errors.
+    pub fn name(&self) -> &'static str { self.name }
+
+    /// The current value of the field, tracked.
+    pub fn get(&self) -> V
+    where
+        V: Clone,
+    {
+        let get = self.get;
+        self.form.value.with(|value| get(value).clone())
+    }
+
+    /// Signal of the value of the field, see [`get`](Self::get).
+    pub fn value(&self) -> Signal<V>
+    where
+        V: Clone + Send + Sync,
+    {
+        let field = *self;
+        Signal::derive(move || field.get())
+    }
+
+    /// Set the value of the field and mark it as changed.
+    pub fn set(&self, value: V) {
+        let get_mut = self.get_mut;
+        self.form.value.update(|form| *get_mut(form) = value);
+        mark(self.form.changed, self.name);
+    }
+
+    /// Mark the field as touched, e.g. when it lost focus.
+    pub fn blur(&self) { mark(self.form.touched, self.name) }
+
+    /// `true` once the field was marked as touched, see [`blur`](Self::blur).
+    pub fn is_touched(&self) -> Signal<bool> {
+        let (touched, name) = (self.form.touched, self.name);
+        Signal::derive(move || touched.with(|touched| touched.contains(name)))
+    }
+
+    /// `true` while the value of the field differs from its initial value.
+    pub fn is_dirty(&self) -> Signal<bool>
+    where
+        V: PartialEq,
+    {
+        let (value, initial, get) = (self.form.value, self.form.initial, self.get);
+        Signal::derive(move || value.with(|value| initial.with_value(|initial| get(value) != get(initial))))
+    }
+
+    /// The errors of the field once they are shown (see [`ValidateMode`]),
+    /// empty before. Includes the errors below the field, e.g.
+    /// `address.street` for a `nested` field `address`.
+    pub fn errors(&self) -> Signal<Vec<ValidationError>> {
+        let field = *self;
+        Signal::derive(move || {
+            if !field.shows_errors() {
+                return Vec::new();
+            }
+            field.form.errors.with(|errors| {
+                errors.iter().filter(|(path, _)| is_under(path, field.name)).flat_map(|(_, errors)| errors.to_vec()).collect()
+            })
+        })
+    }
+
+    /// `true` when the field shows errors, e.g. for `aria-invalid`.
+    pub fn is_invalid(&self) -> Signal<bool> {
+        let field = *self;
+        Signal::derive(move || field.shows_errors() && field.form.errors.with(|errors| errors.iter().any(|(path, _)| is_under(&path, field.name))))
+    }
+
+    fn shows_errors(&self) -> bool {
+        let form = self.form;
+        form.submitted.get()
+            || match form.mode {
+                ValidateMode::OnChange => form.changed.with(|changed| changed.contains(self.name)),
+                ValidateMode::OnBlur => form.touched.with(|touched| touched.contains(self.name)),
+                ValidateMode::OnSubmit => false,
+            }
+    }
+}
+
+/// `true` when the error path `path` is `name` or below it (`name.street`,
+/// `name[0]`).
+fn is_under(path: &str, name: &str) -> bool {
+    path.strip_prefix(name).is_some_and(|rest| rest.is_empty() || rest.starts_with(['.', '[']))
+}
+
+/// Add `name` to the set, notifying only when it was missing.
+fn mark(set: RwSignal<HashSet<&'static str>>, name: &'static str) {
+    if !set.with_untracked(|set| set.contains(name)) {
+        set.update(|set| {
+            set.insert(name);
+        });
+    }
+}
+
+/// The [`FormField`] of a struct field, named like the field:
+/// `form_field!(form, email)` is
+/// `form.field("email", |v| &v.email, |v| &mut v.email)`.
+#[macro_export]
+macro_rules! form_field {
+    ($form:expr, $field:ident) => {
+        $form.field(stringify!($field), |value| &value.$field, |value| &mut value.$field)
+    };
+}
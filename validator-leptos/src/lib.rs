@@ -0,0 +1,12 @@
+//! Leptos form state driven by `validator`.
+//!
+//! [`use_form`] keeps the value of a form in a signal and its errors in a memo
+//! over `complete_validate`. [`FormField`]s read and write single fields and
+//! track whether they were touched or changed; [`ValidateMode`] decides when
+//! their errors are shown. [`TextInput`], [`FieldErrors`] and [`ErrorList`]
+//! render inputs and errors.
+mod components;
+mod form;
+
+pub use components::{ErrorList, FieldErrors, TextInput};
+pub use form::{Form, FormField, ValidateMode, use_form, use_form_with};
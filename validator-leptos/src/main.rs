@@ -1,64 +1,74 @@
-use leptos::html::*;
 use leptos::prelude::*;
 
-use validator::prelude::*;
-use validator::validators::Email;
+use validator::validators::{Email, MinLength, MustMatch};
+use validator_leptos::{ErrorList, FieldErrors, TextInput, ValidateMode, form_field, use_form};
 
-#[component]
-pub fn FormError(error: ReadSignal<Option<ValidationError>>) -> impl IntoView {
-    view! {
-        <Show when=move || error.get().is_some() fallback=|| ()>
-            <p role="alert" class="text-red-600 text-sm mt-1">{ error.get().unwrap().message }</p>
-        </Show>
-    }
+#[derive(Clone, Default, validator::Validate)]
+struct Signup {
+    #[validate(MinLength(2))]
+    name: String,
+    #[validate(Email)]
+    email: String,
+    #[validate(MinLength(8))]
+    password: String,
+    #[validate(MustMatch(other = "password", message = "Passwords do not match"))]
+    confirm_password: String,
 }
 
 #[component]
-pub fn SimpleForm() -> impl IntoView {
-    let (name, set_name) = signal("Unknown".to_string());
-
-    let (_email, set_email) = signal(String::new());
-    let (email_error, set_email_error) = signal(None);
-
-    let email_validator = Email::default();
+pub fn SignupForm() -> impl IntoView {
+    let form = use_form::<Signup>().mode(ValidateMode::OnBlur);
+    let name = form_field!(form, name);
+    let email = form_field!(form, email);
+    let password = form_field!(form, password);
+    let confirm_password = form_field!(form, confirm_password);
+    let (signed_up, set_signed_up) = signal(None::<String>);
 
     view! {
-        <div class="p-4 space-y-4">
-            <h1 class="text-4xl"> Controlled </h1>
-            <h2 class="text-2xl">Hello {name}</h2>
-            <p>
-                Enter your name:
-                <input
-                    type="text"
-                    on:input:target=move |ev| {
-                        set_name.set(ev.target().value());
-                    }
-                    prop:value=name
-                    class="block border rounded px-2 py-1"
-                />
-            </p>
+        <form class="p-4 space-y-4" on:submit=form.on_submit(move |signup| set_signed_up.set(Some(signup.name)))>
+            <h1 class="text-4xl">Sign up</h1>
+            <h2 class="text-2xl">Hello {move || name.get()}</h2>
+
+            <div>
+                <label for="name" class="block font-medium">Name</label>
+                <TextInput field=name />
+                <FieldErrors errors=name.errors() />
+            </div>
 
-            <div class="mt-6">
+            <div>
                 <label for="email" class="block font-medium">Email</label>
-                <input
-                    id="email"
-                    type="email"
-                    class="block border rounded px-2 py-1 w-full"
-                    on:input:target=move |ev| {
-                        let val = ev.target().value();
-                        set_email.set(val.clone());
-                        match email_validator.validate(&val) {
-                            Ok(_) => set_email_error.set(None),
-                            Err(e) => set_email_error.set(Some(e)),
-                        }
-                    }
-                />
-                <FormError error=email_error />
+                <TextInput field=email input_type="email" />
+                <FieldErrors errors=email.errors() />
+            </div>
+
+            <div>
+                <label for="password" class="block font-medium">Password</label>
+                <TextInput field=password input_type="password" />
+                <FieldErrors errors=password.errors() />
+            </div>
+
+            <div>
+                <label for="confirm_password" class="block font-medium">Confirm password</label>
+                <TextInput field=confirm_password input_type="password" />
+                <FieldErrors errors=confirm_password.errors() />
+            </div>
+
+            <Show when=move || form.is_submitted().get()>
+                <ErrorList errors=form.errors() />
+            </Show>
+
+            <div class="space-x-2">
+                <button type="submit" class="border rounded px-2 py-1">Sign up</button>
+                <button type="button" class="border rounded px-2 py-1" on:click=move |_| form.reset()>Reset</button>
             </div>
-        </div>
+
+            <Show when=move || signed_up.get().is_some()>
+                <p class="text-green-700">"Welcome, " {move || signed_up.get()}</p>
+            </Show>
+        </form>
     }
 }
 
 fn main() {
-    mount_to_body(SimpleForm);
-}
\ No newline at end of file
+    mount_to_body(SignupForm);
+}
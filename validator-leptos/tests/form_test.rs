@@ -0,0 +1,115 @@
+use leptos::prelude::*;
+use validator::prelude::ValidationError;
+use validator::validators::{Email, MinLength};
+use validator_leptos::{ValidateMode, form_field, use_form, use_form_with};
+
+#[derive(Debug, Clone, Default, PartialEq, validator::Validate)]
+struct Signup {
+    #[validate(Email)]
+    email: String,
+    #[validate(MinLength(3))]
+    name: String,
+}
+
+fn codes(errors: Signal<Vec<ValidationError>>) -> Vec<String> {
+    errors.get().into_iter().map(|e| e.code.into_owned()).collect()
+}
+
+#[test]
+fn errors_follow_the_value() {
+    Owner::new().set();
+    let form = use_form::<Signup>();
+    let email = form_field!(form, email);
+    assert!(!form.is_valid().get());
+    assert_eq!(form.errors().get().len(), 2);
+
+    email.set("jo@example.com".into());
+    assert_eq!(form.value().get().email, "jo@example.com");
+    assert_eq!(form.errors().get().len(), 1);
+    assert!(!form.errors().get().contains_key("email"));
+
+    form_field!(form, name).set("Joe".into());
+    assert!(form.is_valid().get());
+}
+
+#[test]
+fn modes() {
+    Owner::new().set();
+
+    let form = use_form::<Signup>();
+    let email = form_field!(form, email);
+    email.set("jo".into());
+    assert!(codes(email.errors()).is_empty());
+    email.blur();
+    assert_eq!(codes(email.errors()), ["email"]);
+    assert!(email.is_invalid().get());
+
+    let form = use_form::<Signup>().mode(ValidateMode::OnChange);
+    let email = form_field!(form, email);
+    assert!(codes(email.errors()).is_empty());
+    email.set("jo".into());
+    assert_eq!(codes(email.errors()), ["email"]);
+    email.set("jo@example.com".into());
+    assert!(codes(email.errors()).is_empty());
+
+    let form = use_form::<Signup>().mode(ValidateMode::OnSubmit);
+    let (email, name) = (form_field!(form, email), form_field!(form, name));
+    email.set("jo".into());
+    email.blur();
+    assert!(codes(email.errors()).is_empty());
+    assert_eq!(form.submit(), None);
+    assert!(form.is_submitted().get());
+    assert_eq!(codes(email.errors()), ["email"]);
+    assert_eq!(codes(name.errors()), ["min_length"]);
+}
+
+#[test]
+fn touched_dirty_reset() {
+    Owner::new().set();
+    let initial = Signup { email: "jo@example.com".into(), name: "Joe".into() };
+    let form = use_form_with(initial.clone());
+    let name = form_field!(form, name);
+    assert!(!name.is_touched().get() && !name.is_dirty().get() && !form.is_dirty().get());
+
+    name.blur();
+    name.set("Jo".into());
+    assert!(name.is_touched().get() && name.is_dirty().get() && form.is_dirty().get());
+    assert!(!form_field!(form, email).is_dirty().get());
+    name.set("Joe".into());
+    assert!(!name.is_dirty().get());
+    assert_eq!(form.submit(), Some(initial.clone()));
+
+    name.set("Jo".into());
+    form.reset();
+    assert_eq!(form.value().get(), initial);
+    assert!(!name.is_touched().get() && !form.is_submitted().get());
+}
+
+#[derive(Debug, Clone, Default, PartialEq, validator::Validate)]
+struct Address {
+    #[validate(MinLength(3))]
+    street: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, validator::Validate)]
+struct Order {
+    #[validate(nested)]
+    address: Address,
+    #[validate(MinLength(3))]
+    address_note: String,
+}
+
+#[test]
+fn nested_errors() {
+    Owner::new().set();
+    let form = use_form::<Order>().mode(ValidateMode::OnChange);
+    let address = form_field!(form, address);
+    address.set(Address { street: "M".into() });
+    assert_eq!(codes(address.errors()), ["min_length"]);
+    assert!(address.is_invalid().get());
+
+    address.set(Address { street: "Main".into() });
+    assert!(codes(address.errors()).is_empty());
+    form_field!(form, address_note).set("x".into());
+    assert!(!address.is_invalid().get());
+}
@@ -0,0 +1,331 @@
+use crate::engine::error::ValidationError;
+use crate::engine::errors::{PathSegment, ValidationErrors};
+use crate::engine::state::ValidationState;
+use crate::validate::{Validate, ValidateWith};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Display;
+#[cfg(feature = "async")]
+use crate::validate::{StaticValidateAsync, StaticValidateWithAsync, ValidateAsync, ValidateWithAsync};
+#[cfg(feature = "async")]
+use std::future::Future;
+#[cfg(feature = "async")]
+use std::pin::Pin;
+
+/// Containers whose items can be validated one by one.
+///
+/// Used by the derive macro for `#[validate(each(...))]` and by the `Validate`
+/// impls of the containers below. Every item comes with the path segment that
+/// identifies it inside the container:
+/// - `PathSegment::Index(2)` (`[2]`) for the third item of a `Vec`, slice or array
+/// - `PathSegment::Key("key")` (`["key"]`) for the entry `key` of a map
+/// - no segment for the value of an `Option`
+///
+/// Map entries are returned sorted by key so that validation is deterministic.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a collection supported by `each(...)`",
+    label = "`each(...)` requires `{Self}: Each`",
+    note = "`each(...)` works on `Vec`, slices, arrays, `Option`, `HashMap` and `BTreeMap`"
+)]
+pub trait Each {
+    type Item;
+
+    /// Return the items together with their path segment.
+    fn items(&self) -> Vec<(Option<PathSegment>, &Self::Item)>;
+}
+
+impl<C: Each + ?Sized> Each for &C {
+    type Item = C::Item;
+    fn items(&self) -> Vec<(Option<PathSegment>, &Self::Item)> { (**self).items() }
+}
+
+impl<T> Each for [T] {
+    type Item = T;
+    fn items(&self) -> Vec<(Option<PathSegment>, &T)> {
+        self.iter().enumerate().map(|(i, item)| (Some(PathSegment::Index(i)), item)).collect()
+    }
+}
+
+impl<T, const N: usize> Each for [T; N] {
+    type Item = T;
+    fn items(&self) -> Vec<(Option<PathSegment>, &T)> { self.as_slice().items() }
+}
+
+impl<T> Each for Vec<T> {
+    type Item = T;
+    fn items(&self) -> Vec<(Option<PathSegment>, &T)> { self.as_slice().items() }
+}
+
+impl<T> Each for Option<T> {
+    type Item = T;
+    fn items(&self) -> Vec<(Option<PathSegment>, &T)> {
+        self.iter().map(|item| (None, item)).collect()
+    }
+}
+
+impl<K: Display, V, S> Each for HashMap<K, V, S> {
+    type Item = V;
+    fn items(&self) -> Vec<(Option<PathSegment>, &V)> {
+        let mut items: Vec<_> = self.iter().map(|(k, v)| (Some(PathSegment::Key(k.to_string())), v)).collect();
+        items.sort_by(|a, b| a.0.cmp(&b.0));
+        items
+    }
+}
+
+impl<K: Display, V> Each for BTreeMap<K, V> {
+    type Item = V;
+    fn items(&self) -> Vec<(Option<PathSegment>, &V)> {
+        self.iter().map(|(k, v)| (Some(PathSegment::Key(k.to_string())), v)).collect()
+    }
+}
+
+/// Short-circuit validation of every item of a container.
+fn validate_items<C>(items: &C, state: &mut ValidationState) -> Result<(), ValidationError>
+where
+    C: Each + ?Sized,
+    C::Item: Validate,
+{
+    for (_, item) in items.items() {
+        item.validate_in(state)?;
+    }
+    Ok(())
+}
+
+/// Error-collecting validation of every item of a container. Errors of an item
+/// are nested below its path segment (e.g. `[2].email`).
+fn complete_validate_items<C>(items: &C, state: &mut ValidationState) -> Result<(), ValidationErrors>
+where
+    C: Each + ?Sized,
+    C::Item: Validate,
+{
+    let mut errors = ValidationErrors::new();
+    for (segment, item) in items.items() {
+        if state.is_full() {
+            break;
+        }
+        if let Err(nested) = item.complete_validate_in(state) {
+            errors.nest(segment, nested);
+        }
+    }
+    if errors.is_empty() { Ok(()) } else { Err(errors) }
+}
+
+macro_rules! impl_validate_for_each {
+    ($([$($generics:tt)*] $ty:ty),* $(,)?) => {
+        $(
+            impl<$($generics)*> Validate for $ty
+            where
+                <$ty as Each>::Item: Validate,
+            {
+                fn validate(&self) -> Result<(), ValidationError> {
+                    validate_items(self, &mut ValidationState::new())
+                }
+
+                fn complete_validate(&self) -> Result<(), ValidationErrors> {
+                    complete_validate_items(self, &mut ValidationState::new())
+                }
+
+                fn validate_in(&self, state: &mut ValidationState) -> Result<(), ValidationError> {
+                    validate_items(self, state)
+                }
+
+                fn complete_validate_in(&self, state: &mut ValidationState) -> Result<(), ValidationErrors> {
+                    complete_validate_items(self, state)
+                }
+            }
+
+            impl<Ctx: ?Sized, $($generics)*> ValidateWith<Ctx> for $ty
+            where
+                <$ty as Each>::Item: ValidateWith<Ctx>,
+            {
+                fn validate_with(&self, ctx: &Ctx) -> Result<(), ValidationError> {
+                    self.validate_with_in(ctx, &mut ValidationState::new())
+                }
+
+                fn complete_validate_with(&self, ctx: &Ctx) -> Result<(), ValidationErrors> {
+                    self.complete_validate_with_in(ctx, &mut ValidationState::new())
+                }
+
+                fn validate_with_in(&self, ctx: &Ctx, state: &mut ValidationState) -> Result<(), ValidationError> {
+                    for (_, item) in self.items() {
+                        item.validate_with_in(ctx, state)?;
+                    }
+                    Ok(())
+                }
+
+                fn complete_validate_with_in(&self, ctx: &Ctx, state: &mut ValidationState) -> Result<(), ValidationErrors> {
+                    let mut errors = ValidationErrors::new();
+                    for (segment, item) in self.items() {
+                        if state.is_full() {
+                            break;
+                        }
+                        if let Err(nested) = item.complete_validate_with_in(ctx, state) {
+                            errors.nest(segment, nested);
+                        }
+                    }
+                    if errors.is_empty() { Ok(()) } else { Err(errors) }
+                }
+            }
+
+            #[cfg(feature = "async")]
+            impl<$($generics)*> StaticValidateAsync for $ty
+            where
+                <$ty as Each>::Item: StaticValidateAsync,
+            {
+                fn validate_static(&self) -> impl Future<Output = Result<(), ValidationError>> + '_ {
+                    async move { self.validate_static_in(&mut ValidationState::new()).await }
+                }
+
+                fn complete_validate_static(&self) -> impl Future<Output = Result<(), ValidationErrors>> + '_ {
+                    async move { self.complete_validate_static_in(&mut ValidationState::new()).await }
+                }
+
+                fn validate_static_in<'a>(&'a self, state: &'a mut ValidationState) -> impl Future<Output = Result<(), ValidationError>> + 'a {
+                    async move {
+                        for (_, item) in self.items() {
+                            item.validate_static_in(state).await?;
+                        }
+                        Ok(())
+                    }
+                }
+
+                fn complete_validate_static_in<'a>(&'a self, state: &'a mut ValidationState) -> impl Future<Output = Result<(), ValidationErrors>> + 'a {
+                    async move {
+                        let mut errors = ValidationErrors::new();
+                        for (segment, item) in self.items() {
+                            if state.is_full() {
+                                break;
+                            }
+                            if let Err(nested) = item.complete_validate_static_in(state).await {
+                                errors.nest(segment, nested);
+                            }
+                        }
+                        if errors.is_empty() { Ok(()) } else { Err(errors) }
+                    }
+                }
+            }
+
+            #[cfg(feature = "async")]
+            impl<Ctx: ?Sized, $($generics)*> StaticValidateWithAsync<Ctx> for $ty
+            where
+                <$ty as Each>::Item: StaticValidateWithAsync<Ctx>,
+            {
+                fn validate_with_static<'a>(&'a self, ctx: &'a Ctx) -> impl Future<Output = Result<(), ValidationError>> + 'a {
+                    async move { self.validate_with_static_in(ctx, &mut ValidationState::new()).await }
+                }
+
+                fn complete_validate_with_static<'a>(&'a self, ctx: &'a Ctx) -> impl Future<Output = Result<(), ValidationErrors>> + 'a {
+                    async move { self.complete_validate_with_static_in(ctx, &mut ValidationState::new()).await }
+                }
+
+                fn validate_with_static_in<'a>(&'a self, ctx: &'a Ctx, state: &'a mut ValidationState) -> impl Future<Output = Result<(), ValidationError>> + 'a {
+                    async move {
+                        for (_, item) in self.items() {
+                            item.validate_with_static_in(ctx, state).await?;
+                        }
+                        Ok(())
+                    }
+                }
+
+                fn complete_validate_with_static_in<'a>(&'a self, ctx: &'a Ctx, state: &'a mut ValidationState) -> impl Future<Output = Result<(), ValidationErrors>> + 'a {
+                    async move {
+                        let mut errors = ValidationErrors::new();
+                        for (segment, item) in self.items() {
+                            if state.is_full() {
+                                break;
+                            }
+                            if let Err(nested) = item.complete_validate_with_static_in(ctx, state).await {
+                                errors.nest(segment, nested);
+                            }
+                        }
+                        if errors.is_empty() { Ok(()) } else { Err(errors) }
+                    }
+                }
+            }
+
+            #[cfg(feature = "async")]
+            impl<$($generics)*> ValidateAsync for $ty
+            where
+                <$ty as Each>::Item: ValidateAsync,
+            {
+                fn validate_async(&self) -> Pin<Box<dyn Future<Output = Result<(), ValidationError>> + '_>> {
+                    Box::pin(async move { self.validate_async_in(&mut ValidationState::new()).await })
+                }
+
+                fn complete_validate_async(&self) -> Pin<Box<dyn Future<Output = Result<(), ValidationErrors>> + '_>> {
+                    Box::pin(async move { self.complete_validate_async_in(&mut ValidationState::new()).await })
+                }
+
+                fn validate_async_in<'a>(&'a self, state: &'a mut ValidationState) -> Pin<Box<dyn Future<Output = Result<(), ValidationError>> + 'a>> {
+                    Box::pin(async move {
+                        for (_, item) in self.items() {
+                            item.validate_async_in(state).await?;
+                        }
+                        Ok(())
+                    })
+                }
+
+                fn complete_validate_async_in<'a>(&'a self, state: &'a mut ValidationState) -> Pin<Box<dyn Future<Output = Result<(), ValidationErrors>> + 'a>> {
+                    Box::pin(async move {
+                        let mut errors = ValidationErrors::new();
+                        for (segment, item) in self.items() {
+                            if state.is_full() {
+                                break;
+                            }
+                            if let Err(nested) = item.complete_validate_async_in(state).await {
+                                errors.nest(segment, nested);
+                            }
+                        }
+                        if errors.is_empty() { Ok(()) } else { Err(errors) }
+                    })
+                }
+            }
+
+            #[cfg(feature = "async")]
+            impl<Ctx: ?Sized, $($generics)*> ValidateWithAsync<Ctx> for $ty
+            where
+                <$ty as Each>::Item: ValidateWithAsync<Ctx>,
+            {
+                fn validate_with_async<'a>(&'a self, ctx: &'a Ctx) -> Pin<Box<dyn Future<Output = Result<(), ValidationError>> + 'a>> {
+                    Box::pin(async move { self.validate_with_async_in(ctx, &mut ValidationState::new()).await })
+                }
+
+                fn complete_validate_with_async<'a>(&'a self, ctx: &'a Ctx) -> Pin<Box<dyn Future<Output = Result<(), ValidationErrors>> + 'a>> {
+                    Box::pin(async move { self.complete_validate_with_async_in(ctx, &mut ValidationState::new()).await })
+                }
+
+                fn validate_with_async_in<'a>(&'a self, ctx: &'a Ctx, state: &'a mut ValidationState) -> Pin<Box<dyn Future<Output = Result<(), ValidationError>> + 'a>> {
+                    Box::pin(async move {
+                        for (_, item) in self.items() {
+                            item.validate_with_async_in(ctx, state).await?;
+                        }
+                        Ok(())
+                    })
+                }
+
+                fn complete_validate_with_async_in<'a>(&'a self, ctx: &'a Ctx, state: &'a mut ValidationState) -> Pin<Box<dyn Future<Output = Result<(), ValidationErrors>> + 'a>> {
+                    Box::pin(async move {
+                        let mut errors = ValidationErrors::new();
+                        for (segment, item) in self.items() {
+                            if state.is_full() {
+                                break;
+                            }
+                            if let Err(nested) = item.complete_validate_with_async_in(ctx, state).await {
+                                errors.nest(segment, nested);
+                            }
+                        }
+                        if errors.is_empty() { Ok(()) } else { Err(errors) }
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_validate_for_each! {
+    [T] [T],
+    [T, const N: usize] [T; N],
+    [T] Vec<T>,
+    [T] Option<T>,
+    [K: Display, V, S] HashMap<K, V, S>,
+    [K: Display, V] BTreeMap<K, V>,
+}
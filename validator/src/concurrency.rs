@@ -0,0 +1,88 @@
+//! Helpers for the concurrent execution of async validation, used by the
+//! derive macro.
+//!
+//! Derived `complete_validate_static` runs the fields of a struct with
+//! `futures::join!`, each on a fork of the
+//! [`ValidationState`](crate::prelude::ValidationState), and merges
+//! their errors in field order afterwards. A [`Limiter`] bounds the number of
+//! fields in flight. Nothing is boxed, so the futures stay `Send` when the
+//! validated values allow it.
+use crate::engine::error::ValidationError;
+use futures::future::{self, Either};
+use std::future::Future;
+use std::pin::pin;
+use std::sync::Mutex;
+use std::task::{Poll, Waker};
+use std::time::Duration;
+
+/// Bounds the number of futures running at the same time.
+pub struct Limiter {
+    max: Option<usize>,
+    slots: Mutex<Slots>,
+}
+
+struct Slots {
+    running: usize,
+    waiting: Vec<Waker>,
+}
+
+/// Frees the slot of a running future, also when it is dropped early.
+struct Permit<'a>(&'a Limiter);
+
+impl Drop for Permit<'_> {
+    fn drop(&mut self) {
+        let mut slots = self.0.slots.lock().unwrap_or_else(|e| e.into_inner());
+        slots.running -= 1;
+        for waker in slots.waiting.drain(..) {
+            waker.wake();
+        }
+    }
+}
+
+impl Limiter {
+    /// Run at most `max` futures at a time (no limit with `None`).
+    pub fn new(max: Option<usize>) -> Self {
+        Limiter { max: max.map(|max| max.max(1)), slots: Mutex::new(Slots { running: 0, waiting: Vec::new() }) }
+    }
+
+    /// Await `fut` as soon as a slot is free.
+    pub async fn run<F: Future>(&self, fut: F) -> F::Output {
+        let Some(max) = self.max else { return fut.await };
+        future::poll_fn(|cx| {
+            let mut slots = self.slots.lock().unwrap_or_else(|e| e.into_inner());
+            if slots.running < max {
+                slots.running += 1;
+                Poll::Ready(())
+            } else {
+                slots.waiting.push(cx.waker().clone());
+                Poll::Pending
+            }
+        })
+        .await;
+        let _permit = Permit(self);
+        fut.await
+    }
+}
+
+/// Await the validation `fut`, failing with the `timeout` error code when it
+/// takes longer than `limit`.
+///
+/// Only rules that actually wait are timed: `fut` is polled once first, and a
+/// result ready right away (as from every synchronous validator) is returned
+/// without starting a timer.
+pub async fn timeout<F>(limit: Option<Duration>, fut: F) -> Result<(), ValidationError>
+where
+    F: Future<Output = Result<(), ValidationError>>,
+{
+    let Some(limit) = limit else { return fut.await };
+    let mut fut = pin!(fut);
+    if let Some(result) = future::poll_immediate(&mut fut).await {
+        return result;
+    }
+    match future::select(fut, futures_timer::Delay::new(limit)).await {
+        Either::Left((result, _)) => result,
+        Either::Right(_) => Err(ValidationError::new("timeout", "Validation timed out after {limit_ms} ms")
+            .with_param("limit_ms", limit.as_millis().to_string())
+            .render()),
+    }
+}
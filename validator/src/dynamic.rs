@@ -0,0 +1,263 @@
+//! Validation schemas built at runtime, for data without a Rust type such as
+//! form definitions stored in a database.
+//!
+//! A [`Schema`] attaches the built-in (or your own) validators to the named
+//! fields of a `serde_json::Value` object or of a map of values, and reports
+//! errors the way derived `complete_validate` does: below the field name, with
+//! the `field` param set, items of lists below their index.
+//!
+//! Example
+//! ```
+//! use serde_json::json;
+//! use validator::dynamic::{Field, Schema};
+//! use validator::validators::{Email, MaxLength, MinLength, Range};
+//!
+//! let schema = Schema::new()
+//!     .field("email", Field::new().required().string(Email::default()))
+//!     .field("age", Field::new().number(Range::default().min(18.0)))
+//!     .field("tags", Field::new().list(MaxLength::new(3)).each(Field::new().string(MinLength::new(2))));
+//!
+//! assert!(schema.complete_validate(&json!({ "email": "jo@example.com", "age": 20 })).is_ok());
+//! let errors = schema.complete_validate(&json!({ "age": 16, "tags": ["a", "bb"] })).unwrap_err();
+//! assert_eq!(errors.get("email").map(|e| e[0].code.as_ref()), Some("required"));
+//! assert_eq!(errors.get("age").map(|e| e[0].code.as_ref()), Some("range"));
+//! assert_eq!(errors.get("tags[0]").map(|e| e[0].code.as_ref()), Some("min_length"));
+//! ```
+use crate::engine::error::ValidationError;
+use crate::engine::errors::{PathSegment, ValidationErrors};
+use crate::engine::options::ValidationOptions;
+use crate::engine::state::ValidationState;
+use crate::engine::validator::Validator;
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, HashMap};
+use std::hash::BuildHasher;
+
+/// Values holding named fields: JSON objects and maps of JSON values.
+pub trait Record {
+    /// The value of the field `name`, if present.
+    fn field(&self, name: &str) -> Option<&Value>;
+
+    /// `false` when the value has no fields at all (e.g. a JSON string).
+    fn is_record(&self) -> bool { true }
+}
+
+impl Record for Value {
+    fn field(&self, name: &str) -> Option<&Value> { self.get(name) }
+    fn is_record(&self) -> bool { self.is_object() }
+}
+
+impl Record for Map<String, Value> {
+    fn field(&self, name: &str) -> Option<&Value> { self.get(name) }
+}
+
+impl<S: BuildHasher> Record for HashMap<String, Value, S> {
+    fn field(&self, name: &str) -> Option<&Value> { self.get(name) }
+}
+
+impl Record for BTreeMap<String, Value> {
+    fn field(&self, name: &str) -> Option<&Value> { self.get(name) }
+}
+
+/// The fields of a record and their rules.
+///
+/// Fields are validated in the order they were added; fields of the record
+/// without a [`Field`] in the schema are ignored.
+#[derive(Default)]
+pub struct Schema {
+    fields: Vec<(String, Field)>,
+}
+
+impl Schema {
+    pub fn new() -> Self { Self::default() }
+
+    /// Add the rules of the field `name`.
+    pub fn field(mut self, name: impl Into<String>, field: Field) -> Self {
+        self.fields.push((name.into(), field));
+        self
+    }
+
+    /// Validate `value` and return the first error.
+    pub fn validate<R: Record + ?Sized>(&self, value: &R) -> Result<(), ValidationError> {
+        let mut state = ValidationState::with_options(ValidationOptions::new().max_errors(1));
+        match self.complete_validate_in(value, &mut state) {
+            Ok(()) => Ok(()),
+            Err(errors) => Err(errors.iter().next().map(|(_, errors)| errors[0].clone()).expect("errors are not empty")),
+        }
+    }
+
+    /// Validate `value` and collect all errors.
+    pub fn complete_validate<R: Record + ?Sized>(&self, value: &R) -> Result<(), ValidationErrors> {
+        self.complete_validate_in(value, &mut ValidationState::new())
+    }
+
+    /// [`complete_validate`](Self::complete_validate) with the options of
+    /// `state`, see [`ValidationState::with_options`].
+    pub fn complete_validate_in<R: Record + ?Sized>(&self, value: &R, state: &mut ValidationState) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        if !value.is_record() {
+            errors.add_error(type_error("object"));
+            state.record_error();
+            return Err(errors);
+        }
+        if !state.enter(value) {
+            return state.complete_cycle();
+        }
+        for (name, field) in &self.fields {
+            if state.is_full() {
+                break;
+            }
+            if !state.validates_field(name) {
+                continue;
+            }
+            let mut field_errors = ValidationErrors::new();
+            field.check_value(value.field(name), name, state, &mut field_errors);
+            errors.nest(PathSegment::field(name.clone()), field_errors);
+        }
+        state.exit(value);
+        if errors.is_empty() { Ok(()) } else { Err(errors) }
+    }
+}
+
+/// A rule for values of one JSON type; `None` when the value has another type.
+type Check = Box<dyn Fn(&Value) -> Option<Result<(), ValidationError>> + Send + Sync>;
+
+/// The rules of a field of a [`Schema`] (or of the items of a list field).
+///
+/// Typed rules (`string`, `number`, `integer`, `list`) run validators on the
+/// value converted to that type; a value of another type fails with the `type`
+/// error code and the expected type in the `expected` param. Missing and `null`
+/// values are only checked by [`required`](Self::required).
+#[derive(Default)]
+pub struct Field {
+    required: bool,
+    rules: Vec<(&'static str, Check)>,
+    nested: Option<Schema>,
+    each: Option<Box<Field>>,
+}
+
+impl Field {
+    pub fn new() -> Self { Self::default() }
+
+    /// Fail with the `required` code when the value is missing or `null`.
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    /// Validate string values, e.g. with `Email` or `MinLength`.
+    pub fn string<V: Validator<str> + Send + Sync + 'static>(self, validator: V) -> Self {
+        self.check("string", move |value| value.as_str().map(|s| validator.validate(s)))
+    }
+
+    /// Validate numbers as `f64`, e.g. with `Range` or `Positive`.
+    pub fn number<V: Validator<f64> + Send + Sync + 'static>(self, validator: V) -> Self {
+        self.check("number", move |value| value.as_f64().map(|n| validator.validate(&n)))
+    }
+
+    /// Validate integers as `i64`; other numbers fail with the `type` code.
+    /// Numbers without a fractional part such as `1.0` count as integers (as
+    /// in JSON Schema); integers outside the range of `i64` fail with the
+    /// `integer_range` code.
+    pub fn integer<V: Validator<i64> + Send + Sync + 'static>(self, validator: V) -> Self {
+        self.check("integer", move |value| as_integer(value).map(|n| validator.validate(&n?)))
+    }
+
+    /// Validate arrays as a whole, e.g. their length with `MaxLength`.
+    pub fn list<V: Validator<[Value]> + Send + Sync + 'static>(self, validator: V) -> Self {
+        self.check("array", move |value| value.as_array().map(|items| validator.validate(items.as_slice())))
+    }
+
+    /// Validate the value as it is, whatever its type.
+    pub fn rule<V: Validator<Value> + Send + Sync + 'static>(self, validator: V) -> Self {
+        self.check("value", move |value| Some(validator.validate(value)))
+    }
+
+    /// Validate objects with their own schema; errors are reported below the
+    /// field (`address.street`).
+    pub fn nested(mut self, schema: Schema) -> Self {
+        self.nested = Some(schema);
+        self
+    }
+
+    /// Validate every item of an array with `field`; errors are reported below
+    /// the index of the item (`tags[2]`).
+    pub fn each(mut self, field: Field) -> Self {
+        self.each = Some(Box::new(field));
+        self
+    }
+
+    fn check(mut self, expected: &'static str, check: impl Fn(&Value) -> Option<Result<(), ValidationError>> + Send + Sync + 'static) -> Self {
+        self.rules.push((expected, Box::new(check)));
+        self
+    }
+
+    /// Run the rules on `value`, adding the errors to `errors`. `name` is the
+    /// name of the field, reported as `field` param. Stops at the first value
+    /// of an unexpected type.
+    fn check_value(&self, value: Option<&Value>, name: &str, state: &mut ValidationState, errors: &mut ValidationErrors) {
+        let Some(value) = value.filter(|value| !value.is_null()) else {
+            if self.required && state.runs_rule(errors) {
+                report(errors, state, ValidationError::new("required", "Value is required"), name);
+            }
+            return;
+        };
+        for (expected, check) in &self.rules {
+            if !state.runs_rule(errors) {
+                return;
+            }
+            match check(value) {
+                Some(Ok(())) => {}
+                Some(Err(e)) => report(errors, state, e, name),
+                None => return report(errors, state, type_error(expected), name),
+            }
+        }
+        if let Some(schema) = &self.nested
+            && state.runs_rule(errors)
+        {
+            if !value.is_object() {
+                return report(errors, state, type_error("object"), name);
+            }
+            if let Err(nested) = schema.complete_validate_in(value, state) {
+                errors.merge(nested);
+            }
+        }
+        if let Some(item) = &self.each
+            && state.runs_rule(errors)
+        {
+            let Some(items) = value.as_array() else {
+                return report(errors, state, type_error("array"), name);
+            };
+            for (index, value) in items.iter().enumerate() {
+                if state.is_full() {
+                    break;
+                }
+                let mut item_errors = ValidationErrors::new();
+                item.check_value(Some(value), name, state, &mut item_errors);
+                errors.nest(PathSegment::Index(index), item_errors);
+            }
+        }
+    }
+}
+
+/// Add the error of a rule of the field `name` and count it.
+fn report(errors: &mut ValidationErrors, state: &mut ValidationState, error: ValidationError, name: &str) {
+    errors.add_error(error.with_field(name.to_string()));
+    state.record_error();
+}
+
+/// `value` as `i64`, `None` when it is no integer.
+fn as_integer(value: &Value) -> Option<Result<i64, ValidationError>> {
+    if let Some(n) = value.as_i64() {
+        return Some(Ok(n));
+    }
+    let n = value.as_f64().filter(|n| n.fract() == 0.0)?;
+    // `i64::MAX as f64` rounds up to 2^63, just outside the range
+    if value.is_u64() || n < i64::MIN as f64 || n >= i64::MAX as f64 {
+        return Some(Err(ValidationError::new("integer_range", "Value is out of the range of 64-bit integers")));
+    }
+    Some(Ok(n as i64))
+}
+
+fn type_error(expected: &'static str) -> ValidationError {
+    ValidationError::new("type", "Expected a value of type {expected}").with_param("expected", expected).render()
+}
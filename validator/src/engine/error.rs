@@ -1,10 +1,17 @@
+use crate::engine::message::render_message;
 use std::borrow::Cow;
 use std::collections::BTreeMap;
 
+/// A single failed rule.
+///
+/// With the `serde` feature it (de)serializes as
+/// `{ "code": "max_length", "message": "...", "params": { "limit": "10", "len": "13" } }`.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ValidationError {
     pub code: Cow<'static, str>,
     pub message: Cow<'static, str>,
+    #[cfg_attr(feature = "serde", serde(default))]
     pub params: BTreeMap<Cow<'static, str>, Cow<'static, str>>,
 }
 
@@ -17,6 +24,28 @@ impl ValidationError {
         self.params.insert(key.into(), value.into());
         self
     }
+
+    /// Render `{param}` placeholders of the message against the params
+    /// (see [`render_message`]).
+    pub fn render(mut self) -> Self {
+        self.message = render_message(&self.message, &self.params).into();
+        self
+    }
+
+    /// Record the param `key` and render only its `{key}` placeholders, for
+    /// params known after the message was rendered. Other placeholders are
+    /// not rendered again, so param values looking like placeholders stay as
+    /// they are.
+    pub fn with_rendered_param(mut self, key: impl Into<Cow<'static, str>>, value: impl Into<Cow<'static, str>>) -> Self {
+        let param = BTreeMap::from([(key.into(), value.into())]);
+        self.message = render_message(&self.message, &param).into();
+        self.params.extend(param);
+        self
+    }
+
+    /// Record the name of the validated field as the `field` param and render
+    /// `{field}` placeholders left in the message.
+    pub fn with_field(self, field: impl Into<Cow<'static, str>>) -> Self { self.with_rendered_param("field", field) }
 }
 
 impl core::fmt::Display for ValidationError {
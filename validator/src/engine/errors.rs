@@ -0,0 +1,299 @@
+use crate::engine::error::ValidationError;
+use crate::engine::i18n::MessageResolver;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+/// One step of the path leading to a validated value.
+///
+/// Paths render like Rust accessors: fields are joined with `.`, list
+/// positions as `[2]` and map keys as `["key"]`, e.g.
+/// `members[2].address.street` or `tags["x"]`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PathSegment {
+    /// A named struct field or the index of a tuple struct field.
+    Field(Cow<'static, str>),
+    /// The position of an item in a list, slice or array.
+    Index(usize),
+    /// The key of an entry in a map.
+    Key(String),
+}
+
+impl PathSegment {
+    pub fn field(name: impl Into<Cow<'static, str>>) -> Self { PathSegment::Field(name.into()) }
+}
+
+impl From<&'static str> for PathSegment {
+    fn from(name: &'static str) -> Self { PathSegment::Field(Cow::Borrowed(name)) }
+}
+
+impl From<usize> for PathSegment {
+    fn from(index: usize) -> Self { PathSegment::Index(index) }
+}
+
+/// All errors found while validating a value, organised as a tree.
+///
+/// Every node holds the errors reported for the value itself and the errors of
+/// its children (struct fields, list items, map entries). For a struct this
+/// means:
+/// - field errors live in the child node of the field
+/// - errors of nested structs and collections live below that child node
+/// - struct-level errors live in the root node and render as `__all__`
+///
+/// Children are kept sorted, so iteration and `Display` are deterministic.
+///
+/// With the `serde` feature the tree (de)serializes as a flat object keyed by
+/// path, in the same order as [`ValidationErrors::iter`]:
+/// ```json
+/// {
+///   "__all__": [{ "code": "schema", "message": "...", "params": {} }],
+///   "address.street": [{ "code": "min_length", "message": "...", "params": { "len": "2", "limit": "3" } }],
+///   "emails[1]": [{ "code": "email", "message": "Invalid email format", "params": { "field": "emails", "reason": "format" } }]
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidationErrors {
+    errors: Vec<ValidationError>,
+    children: BTreeMap<PathSegment, ValidationErrors>,
+}
+
+impl ValidationErrors {
+    /// Path under which errors of the root value itself are reported.
+    pub const STRUCT_KEY: &'static str = "__all__";
+
+    pub fn new() -> Self { Self::default() }
+
+    /// `true` when neither this node nor any child holds an error.
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty() && self.children.values().all(ValidationErrors::is_empty)
+    }
+
+    /// Number of distinct paths carrying at least one error.
+    pub fn len(&self) -> usize {
+        usize::from(!self.errors.is_empty()) + self.children.values().map(ValidationErrors::len).sum::<usize>()
+    }
+
+    /// Add an error for the value itself (struct-level error).
+    pub fn add_error(&mut self, error: ValidationError) {
+        self.errors.push(error);
+    }
+
+    /// Add an error for the field `field`.
+    pub fn add(&mut self, field: impl Into<Cow<'static, str>>, error: ValidationError) {
+        self.children.entry(PathSegment::field(field)).or_default().add_error(error);
+    }
+
+    /// Attach the errors of a child value below `segment`. With `None` the
+    /// errors are merged into this node (used for `Option` values). Empty
+    /// child errors are ignored.
+    pub fn nest(&mut self, segment: impl Into<Option<PathSegment>>, child: ValidationErrors) {
+        if child.is_empty() {
+            return;
+        }
+        match segment.into() {
+            Some(segment) => self.children.entry(segment).or_default().merge(child),
+            None => self.merge(child),
+        }
+    }
+
+    /// Merge all errors of `other` into this tree.
+    pub fn merge(&mut self, other: ValidationErrors) {
+        self.errors.extend(other.errors);
+        for (segment, child) in other.children {
+            self.nest(segment, child);
+        }
+    }
+
+    /// Errors reported for the value itself.
+    pub fn errors(&self) -> &[ValidationError] { &self.errors }
+
+    /// Direct children with their errors.
+    pub fn children(&self) -> impl Iterator<Item = (&PathSegment, &ValidationErrors)> {
+        self.children.iter().filter(|(_, child)| !child.is_empty())
+    }
+
+    /// Errors of the child below `segment`.
+    pub fn child(&self, segment: &PathSegment) -> Option<&ValidationErrors> {
+        self.children.get(segment).filter(|child| !child.is_empty())
+    }
+
+    /// Errors reported for `path` (e.g. `address.street` or `emails[2]`).
+    /// `__all__` returns the struct-level errors.
+    pub fn get(&self, path: &str) -> Option<&[ValidationError]> {
+        let node = if path == Self::STRUCT_KEY {
+            self
+        } else {
+            parse_path(path)?.iter().try_fold(self, |node, segment| node.children.get(segment))?
+        };
+        if node.errors.is_empty() { None } else { Some(&node.errors) }
+    }
+
+    /// `true` when errors are reported for `path`.
+    pub fn contains_key(&self, path: &str) -> bool { self.get(path).is_some() }
+
+    /// Iterate over all paths with errors in a deterministic order (a node's
+    /// own errors before those of its children, children sorted).
+    pub fn iter(&self) -> impl Iterator<Item = (String, &[ValidationError])> {
+        let mut out = Vec::new();
+        self.collect_paths(&mut String::new(), &mut out);
+        out.into_iter()
+    }
+
+    /// Return a copy of the tree with every message localized by `resolver`.
+    pub fn localize<R: MessageResolver + ?Sized>(&self, resolver: &R, locale: &str) -> ValidationErrors {
+        ValidationErrors {
+            errors: self
+                .errors
+                .iter()
+                .map(|e| ValidationError { message: resolver.resolve(locale, e).into(), ..e.clone() })
+                .collect(),
+            children: self
+                .children
+                .iter()
+                .map(|(segment, child)| (segment.clone(), child.localize(resolver, locale)))
+                .collect(),
+        }
+    }
+
+    fn collect_paths<'a>(&'a self, path: &mut String, out: &mut Vec<(String, &'a [ValidationError])>) {
+        if !self.errors.is_empty() {
+            let key = if path.is_empty() { Self::STRUCT_KEY.to_string() } else { path.clone() };
+            out.push((key, &self.errors));
+        }
+        for (segment, child) in &self.children {
+            let len = path.len();
+            push_segment(path, segment);
+            child.collect_paths(path, out);
+            path.truncate(len);
+        }
+    }
+}
+
+impl core::fmt::Display for ValidationErrors {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut first = true;
+        for (path, errors) in self.iter() {
+            for error in errors {
+                if !first { writeln!(f)?; }
+                first = false;
+                write!(f, "{}: {}", path, error)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ValidationErrors {}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ValidationErrors {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ValidationErrors {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let flat: BTreeMap<String, Vec<ValidationError>> = serde::Deserialize::deserialize(deserializer)?;
+        let mut errors = ValidationErrors::new();
+        for (path, errs) in flat {
+            let segments = if path == Self::STRUCT_KEY {
+                Vec::new()
+            } else {
+                parse_path(&path).ok_or_else(|| serde::de::Error::custom(format!("invalid error path `{}`", path)))?
+            };
+            let node = segments
+                .into_iter()
+                .fold(&mut errors, |node, segment| node.children.entry(segment).or_default());
+            node.errors.extend(errs);
+        }
+        Ok(errors)
+    }
+}
+
+fn push_segment(path: &mut String, segment: &PathSegment) {
+    match segment {
+        PathSegment::Field(name) => {
+            if !path.is_empty() { path.push('.'); }
+            path.push_str(name);
+        }
+        PathSegment::Index(index) => path.push_str(&format!("[{}]", index)),
+        PathSegment::Key(key) => path.push_str(&format!("[{:?}]", key)),
+    }
+}
+
+/// Parse a path string as produced by [`ValidationErrors::iter`] back into
+/// segments. Returns `None` for malformed paths.
+pub(crate) fn parse_path(path: &str) -> Option<Vec<PathSegment>> {
+    let mut segments = Vec::new();
+    let mut chars = path.chars().peekable();
+    let mut field = String::new();
+    while let Some(c) = chars.next() {
+        match c {
+            '.' => {
+                if field.is_empty() { return None; }
+                segments.push(PathSegment::field(core::mem::take(&mut field)));
+            }
+            '[' => {
+                if !field.is_empty() {
+                    segments.push(PathSegment::field(core::mem::take(&mut field)));
+                }
+                if chars.peek() == Some(&'"') {
+                    chars.next();
+                    segments.push(PathSegment::Key(parse_quoted(&mut chars)?));
+                    if chars.next() != Some(']') { return None; }
+                } else {
+                    let mut digits = String::new();
+                    loop {
+                        match chars.next()? {
+                            ']' => break,
+                            c => digits.push(c),
+                        }
+                    }
+                    segments.push(PathSegment::Index(digits.parse().ok()?));
+                }
+                // after `]` only `.`, `[` or the end may follow
+                if chars.peek() == Some(&'.') {
+                    chars.next();
+                    chars.peek()?;
+                }
+            }
+            _ => field.push(c),
+        }
+    }
+    if !field.is_empty() {
+        segments.push(PathSegment::field(field));
+    } else if path.ends_with('.') {
+        return None;
+    }
+    Some(segments)
+}
+
+/// Parse the rest of a `"..."` string with Rust debug escapes.
+fn parse_quoted(chars: &mut core::iter::Peekable<core::str::Chars<'_>>) -> Option<String> {
+    let mut out = String::new();
+    loop {
+        match chars.next()? {
+            '"' => return Some(out),
+            '\\' => match chars.next()? {
+                'n' => out.push('\n'),
+                'r' => out.push('\r'),
+                't' => out.push('\t'),
+                '0' => out.push('\0'),
+                'u' => {
+                    if chars.next()? != '{' { return None; }
+                    let mut hex = String::new();
+                    loop {
+                        match chars.next()? {
+                            '}' => break,
+                            c => hex.push(c),
+                        }
+                    }
+                    out.push(char::from_u32(u32::from_str_radix(&hex, 16).ok()?)?);
+                }
+                c => out.push(c),
+            },
+            c => out.push(c),
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use crate::engine::error::ValidationError;
+use crate::engine::message::render_message;
+use std::collections::HashMap;
+
+/// Turns locale-neutral errors into localized text.
+///
+/// `ValidationError` only carries a `code` and `params`; the UI layer picks a
+/// locale and asks a resolver for the matching message. The returned template
+/// is rendered against the error params like any other message (see
+/// [`render_message`]).
+pub trait MessageResolver {
+    /// Return the message template for `code` in `locale`, or `None` when the
+    /// resolver has no translation.
+    fn template(&self, locale: &str, code: &str) -> Option<&str>;
+
+    /// Localize `error`, falling back to its own message when no template exists.
+    fn resolve(&self, locale: &str, error: &ValidationError) -> String {
+        match self.template(locale, &error.code) {
+            Some(template) => render_message(template, &error.params),
+            None => error.message.to_string(),
+        }
+    }
+}
+
+/// Built-in [`MessageResolver`] holding message templates per locale and code.
+///
+/// Lookups try the exact locale (`fr-LU`), then its language (`fr`), then the
+/// fallback locale if one is set.
+///
+/// Catalogs can be built in code, loaded from Fluent-style files with
+/// [`MessageCatalog::add_ftl`], or (with the `serde` feature) deserialized from
+/// any serde format such as JSON or TOML:
+/// ```json
+/// { "fallback": "en", "locales": { "fr": { "email": "Adresse e-mail invalide" } } }
+/// ```
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessageCatalog {
+    #[cfg_attr(feature = "serde", serde(default))]
+    fallback: Option<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    locales: HashMap<String, HashMap<String, String>>,
+}
+
+impl MessageCatalog {
+    pub fn new() -> Self { Self::default() }
+
+    /// Use `locale` when neither the requested locale nor its language match.
+    pub fn with_fallback(mut self, locale: impl Into<String>) -> Self {
+        self.fallback = Some(locale.into());
+        self
+    }
+
+    /// Add (or replace) the template for `code` in `locale`.
+    pub fn insert(&mut self, locale: impl Into<String>, code: impl Into<String>, template: impl Into<String>) {
+        self.locales.entry(locale.into()).or_default().insert(code.into(), template.into());
+    }
+
+    /// Builder variant of [`MessageCatalog::insert`].
+    pub fn with(mut self, locale: impl Into<String>, code: impl Into<String>, template: impl Into<String>) -> Self {
+        self.insert(locale, code, template);
+        self
+    }
+
+    /// Load the templates of `locale` from a Fluent-style source:
+    /// ```text
+    /// # comments and blank lines are ignored
+    /// email = Adresse e-mail invalide
+    /// max_length = { $field } : { $limit } caractères au maximum
+    /// ```
+    /// Both `{ $param }` (Fluent) and `{param}` placeholders are accepted.
+    /// Returns the line number of the first malformed line as error.
+    pub fn add_ftl(&mut self, locale: &str, source: &str) -> Result<(), usize> {
+        for (idx, line) in source.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') { continue; }
+            let (code, template) = line.split_once('=').ok_or(idx + 1)?;
+            let code = code.trim();
+            if code.is_empty() { return Err(idx + 1); }
+            self.insert(locale, code, fluent_placeholders(template.trim()));
+        }
+        Ok(())
+    }
+
+    fn lookup(&self, locale: &str, code: &str) -> Option<&str> {
+        self.locales.get(locale)?.get(code).map(String::as_str)
+    }
+}
+
+impl MessageResolver for MessageCatalog {
+    fn template(&self, locale: &str, code: &str) -> Option<&str> {
+        let language = locale.split(['-', '_']).next().unwrap_or(locale);
+        self.lookup(locale, code)
+            .or_else(|| self.lookup(language, code))
+            .or_else(|| self.fallback.as_deref().and_then(|fallback| self.lookup(fallback, code)))
+    }
+}
+
+/// Rewrite Fluent placeholders (`{ $limit }`) to the `{limit}` form.
+fn fluent_placeholders(template: &str) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        match after.find('}') {
+            Some(end) if after[..end].trim().starts_with('$') => {
+                out.push('{');
+                out.push_str(after[..end].trim().trim_start_matches('$'));
+                out.push('}');
+                rest = &after[end + 1..];
+            }
+            _ => {
+                out.push('{');
+                rest = after;
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
@@ -0,0 +1,38 @@
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+/// Render a message template against error params.
+///
+/// Every `{name}` placeholder whose `name` is a key of `params` is replaced by
+/// the param value; unknown placeholders are kept as they are, so a message
+/// can be rendered again once more params (e.g. `field`) are known.
+///
+/// Example
+/// ```
+/// use std::collections::BTreeMap;
+/// use validator::prelude::render_message;
+///
+/// let mut params = BTreeMap::new();
+/// params.insert("limit".into(), "10".into());
+/// assert_eq!(render_message("At most {limit} ({field})", &params), "At most 10 ({field})");
+/// ```
+pub fn render_message(template: &str, params: &BTreeMap<Cow<'static, str>, Cow<'static, str>>) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        match after.find('}') {
+            Some(end) if params.contains_key(&after[..end]) => {
+                out.push_str(&params[&after[..end]]);
+                rest = &after[end + 1..];
+            }
+            _ => {
+                out.push('{');
+                rest = after;
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
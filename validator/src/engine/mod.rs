@@ -1,2 +1,7 @@
 pub mod validator;
-pub mod error;
\ No newline at end of file
+pub mod error;
+pub mod errors;
+pub mod message;
+pub mod i18n;
+pub mod options;
+pub mod state;
\ No newline at end of file
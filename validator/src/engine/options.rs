@@ -0,0 +1,79 @@
+use std::time::Duration;
+
+/// Options tuning a validation run, passed in with
+/// [`ValidationState::with_options`](crate::prelude::ValidationState::with_options).
+///
+/// - `max_errors`: stop collecting once this many errors were reported. The
+///   count depends on the order of the errors, so `complete_validate_async`
+///   validates the fields one after another when it is set
+/// - `stop_on_first_error_per_field`: in error-collecting mode, skip the
+///   remaining rules of a field (or collection item) after its first error
+/// - `skip_fields` / `only_fields`: skip the rules of the listed fields, or of
+///   all fields not listed, e.g. to validate only the fields sent with a PATCH
+///   request. They apply to the fields of the outermost validated values;
+///   struct-level rules always run.
+/// - `max_concurrency`: in `complete_validate_async`, the number of fields
+///   validated at the same time (all by default). It has no effect when
+///   `max_errors` is set, as the fields then run one after another.
+/// - `timeout`: in async validation, the time an async validator or custom
+///   function may take before failing with the `timeout` error code. Rules
+///   that complete without waiting, like synchronous validators, never time
+///   out
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidationOptions {
+    pub max_errors: Option<usize>,
+    pub stop_on_first_error_per_field: bool,
+    pub skip_fields: Vec<String>,
+    pub only_fields: Option<Vec<String>>,
+    pub max_concurrency: Option<usize>,
+    pub timeout: Option<Duration>,
+}
+
+impl ValidationOptions {
+    pub fn new() -> Self { Self::default() }
+    pub fn max_errors(mut self, max_errors: usize) -> Self {
+        self.max_errors = Some(max_errors);
+        self
+    }
+    pub fn stop_on_first_error_per_field(mut self, stop: bool) -> Self {
+        self.stop_on_first_error_per_field = stop;
+        self
+    }
+    pub fn skip_fields<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.skip_fields = fields.into_iter().map(Into::into).collect();
+        self
+    }
+    pub fn only_fields<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.only_fields = Some(fields.into_iter().map(Into::into).collect());
+        self
+    }
+    pub fn max_concurrency(mut self, max_concurrency: usize) -> Self {
+        self.max_concurrency = Some(max_concurrency);
+        self
+    }
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// `true` when async error-collecting validation runs the fields of a
+    /// struct concurrently: unless `max_errors` is set or `max_concurrency`
+    /// is 1.
+    pub fn validates_fields_concurrently(&self) -> bool {
+        self.max_errors.is_none() && self.max_concurrency != Some(1)
+    }
+
+    /// `true` when the rules of `field` run according to the field filters.
+    pub fn includes_field(&self, field: &str) -> bool {
+        !self.skip_fields.iter().any(|f| f == field)
+            && self.only_fields.as_ref().is_none_or(|only| only.iter().any(|f| f == field))
+    }
+}
@@ -0,0 +1,141 @@
+use crate::engine::error::ValidationError;
+use crate::engine::errors::ValidationErrors;
+use crate::engine::options::ValidationOptions;
+
+/// State carried through one validation run.
+///
+/// Tracks the values currently being validated, i.e. the path from the root
+/// value to the value at hand, identified by address and type. A value that is
+/// reached again while it is still being validated closes a cycle and is not
+/// validated a second time. Values reachable along several paths without
+/// being their own ancestor are validated every time they are reached.
+///
+/// Cycles are accepted by default; with `report_cycles(true)` they fail with
+/// the `cycle` error code. Pass the state to `Validate::validate_in` (and its
+/// `complete`/async/context counterparts) to choose the behavior.
+///
+/// The state also holds the [`ValidationOptions`] of the run and counts the
+/// reported errors for `max_errors`.
+#[derive(Debug, Default)]
+pub struct ValidationState {
+    path: Vec<(usize, &'static str)>,
+    depth: usize,
+    report_cycles: bool,
+    options: ValidationOptions,
+    error_count: usize,
+    forked_at: usize,
+}
+
+impl ValidationState {
+    pub fn new() -> Self { Self::default() }
+
+    pub fn with_options(options: ValidationOptions) -> Self {
+        Self { options, ..Self::default() }
+    }
+
+    pub fn options(&self) -> &ValidationOptions { &self.options }
+
+    /// Report values closing a cycle with the `cycle` error code instead of
+    /// accepting them.
+    pub fn report_cycles(mut self, report_cycles: bool) -> Self {
+        self.report_cycles = report_cycles;
+        self
+    }
+
+    /// Start validating `value`. Returns `false` when `value` is already being
+    /// validated further up the path, in which case it must not be entered.
+    pub fn enter<T: ?Sized>(&mut self, value: &T) -> bool {
+        if let Some(key) = identity(value) {
+            if self.path.contains(&key) {
+                return false;
+            }
+            self.path.push(key);
+        }
+        self.depth += 1;
+        true
+    }
+
+    /// Finish validating `value` after a successful [`enter`](Self::enter).
+    pub fn exit<T: ?Sized>(&mut self, value: &T) {
+        self.depth = self.depth.saturating_sub(1);
+        let Some(key) = identity(value) else { return };
+        if let Some(pos) = self.path.iter().rposition(|entry| *entry == key) {
+            self.path.truncate(pos);
+        }
+    }
+
+    /// Short-circuit result for a value closing a cycle.
+    pub fn cycle(&self) -> Result<(), ValidationError> {
+        if self.report_cycles { Err(cycle_error()) } else { Ok(()) }
+    }
+
+    /// Error-collecting result for a value closing a cycle.
+    pub fn complete_cycle(&mut self) -> Result<(), ValidationErrors> {
+        if !self.report_cycles || self.is_full() {
+            return Ok(());
+        }
+        self.record_error();
+        let mut errors = ValidationErrors::new();
+        errors.add_error(cycle_error());
+        Err(errors)
+    }
+
+    /// `true` when the rules of `field` of the value being validated run. The
+    /// field filters of the options only apply to the outermost value.
+    pub fn validates_field(&self, field: &str) -> bool {
+        self.depth > 1 || self.options.includes_field(field)
+    }
+
+    /// `true` when the next rule of a value that already has the errors
+    /// `errors` runs in error-collecting mode.
+    pub fn runs_rule(&self, errors: &ValidationErrors) -> bool {
+        !self.is_full() && (!self.options.stop_on_first_error_per_field || errors.is_empty())
+    }
+
+    /// `true` once `max_errors` errors were reported.
+    pub fn is_full(&self) -> bool {
+        self.options.max_errors.is_some_and(|max| self.error_count >= max)
+    }
+
+    /// Count an error reported by a rule.
+    pub fn record_error(&mut self) {
+        self.error_count += 1;
+    }
+
+    /// Count the errors of a value validated without the state.
+    pub fn record_errors(&mut self, errors: &ValidationErrors) {
+        self.error_count += errors.iter().map(|(_, errors)| errors.len()).sum::<usize>();
+    }
+
+    /// Copy of the state for validating a part of the current value on its
+    /// own, e.g. concurrently with the other parts. Hand it back with
+    /// [`join`](Self::join) afterwards.
+    pub fn fork(&self) -> Self {
+        ValidationState {
+            path: self.path.clone(),
+            depth: self.depth,
+            report_cycles: self.report_cycles,
+            options: self.options.clone(),
+            error_count: self.error_count,
+            forked_at: self.error_count,
+        }
+    }
+
+    /// Take over the errors counted by a state created with [`fork`](Self::fork).
+    pub fn join(&mut self, fork: ValidationState) {
+        self.error_count += fork.error_count - fork.forked_at;
+    }
+}
+
+/// Address and type name of `value`. The type tells apart a struct and its
+/// first field, which share the address. Zero-sized values have no identity.
+fn identity<T: ?Sized>(value: &T) -> Option<(usize, &'static str)> {
+    if core::mem::size_of_val(value) == 0 {
+        return None;
+    }
+    Some((value as *const T as *const () as usize, core::any::type_name::<T>()))
+}
+
+fn cycle_error() -> ValidationError {
+    ValidationError::new("cycle", "Value refers back to itself")
+}
@@ -18,17 +18,129 @@ use std::pin::Pin;
 /// assert!(v.validate(&10).is_ok());
 /// assert!(v.validate(&0).is_err());
 /// ```
-pub trait Validator<T> {
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot validate values of type `{T}`",
+    label = "`{Self}` does not implement `Validator<{T}>`",
+    note = "validators in `#[validate(...)]` must implement `Validator<FieldType>`; use `each(...)` to validate the items of a collection"
+)]
+pub trait Validator<T: ?Sized> {
     /// Validate the provided `value`.
     fn validate(&self, value: &T) -> Result<(), ValidationError>;
 }
 
+/// A validator that needs runtime data (the current tenant, a reference date,
+/// a DB handle, ...) passed as context of type `C`.
+///
+/// Used by the derive macro on structs with `#[validate(context = "C")]`, which
+/// implement [`ValidateWith<C>`](crate::validate::ValidateWith). Every
+/// `Validator<T>` is a `ContextValidator<T, C>` for any context, so context-free
+/// validators keep working on those structs.
+///
+/// Example
+/// ```
+/// use validator::prelude::*;
+///
+/// struct Tenant { domain: String }
+/// struct TenantDomain;
+///
+/// impl ContextValidator<String, Tenant> for TenantDomain {
+///     fn validate_with(&self, value: &String, tenant: &Tenant) -> Result<(), ValidationError> {
+///         if value.ends_with(&tenant.domain) { Ok(()) } else { Err(ValidationError::new("tenant_domain", "Wrong domain")) }
+///     }
+/// }
+///
+/// let tenant = Tenant { domain: "@example.com".to_string() };
+/// assert!(TenantDomain.validate_with(&"john@example.com".to_string(), &tenant).is_ok());
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot validate values of type `{T}` with context `{C}`",
+    label = "`{Self}` implements neither `Validator<{T}>` nor `ContextValidator<{T}, {C}>`"
+)]
+pub trait ContextValidator<T: ?Sized, C: ?Sized> {
+    /// Validate the provided `value` with the context `ctx`.
+    fn validate_with(&self, value: &T, ctx: &C) -> Result<(), ValidationError>;
+}
+
+impl<V, T, C> ContextValidator<T, C> for V
+where
+    V: Validator<T> + ?Sized,
+    T: ?Sized,
+    C: ?Sized,
+{
+    fn validate_with(&self, value: &T, _ctx: &C) -> Result<(), ValidationError> {
+        self.validate(value)
+    }
+}
+
+/// A validator comparing a value with another field of the same struct.
+///
+/// Used by the derive macro for validator items naming a sibling field with
+/// `other = "..."` or `field = "..."`, e.g. `MustMatch(other = "password")`.
+/// The error is reported for the annotated field and gets the name of the
+/// sibling as `other` param.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot compare values of type `{T}` with `{U}`",
+    label = "`{Self}` does not implement `CrossFieldValidator<{T}, {U}>`"
+)]
+pub trait CrossFieldValidator<T: ?Sized, U: ?Sized> {
+    /// Validate `value` against the sibling field value `other`.
+    fn validate(&self, value: &T, other: &U) -> Result<(), ValidationError>;
+}
+
+/// An asynchronous validator for static dispatch, next to [`AsyncValidator`].
+///
+/// Implement it with an `async fn`; the future is not boxed, and it is `Send`
+/// whenever its captures are, so derived `validate_static` futures can be
+/// awaited in `tokio::spawn` and web handlers when the fields and validators
+/// are `Sync`. Any synchronous `Validator<T>` is also a
+/// `StaticAsyncValidator<T>` via a blanket impl. The derive calls it in place
+/// of `AsyncValidator` when a validator implements both.
+///
+/// Example
+/// ```
+/// use validator::prelude::*;
+///
+/// struct NotReserved;
+///
+/// impl StaticAsyncValidator<String> for NotReserved {
+///     async fn validate_static(&self, value: &String) -> Result<(), ValidationError> {
+///         // e.g. a lookup in a remote service
+///         if value == "admin" { Err(ValidationError::new("reserved", "Name is reserved")) } else { Ok(()) }
+///     }
+/// }
+///
+/// let err = validator::futures::executor::block_on(NotReserved.validate_static(&"admin".to_string()));
+/// assert_eq!(err.unwrap_err().code, "reserved");
+/// ```
+#[cfg(feature = "async")]
+pub trait StaticAsyncValidator<T: ?Sized> {
+    /// Asynchronously validate the provided `value`.
+    fn validate_static<'a>(&'a self, value: &'a T) -> impl Future<Output = Result<(), ValidationError>> + 'a;
+}
+
+#[cfg(feature = "async")]
+impl<V, T> StaticAsyncValidator<T> for V
+where
+    V: Validator<T>,
+    T: ?Sized,
+{
+    async fn validate_static(&self, value: &T) -> Result<(), ValidationError> {
+        self.validate(value)
+    }
+}
+
 /// An asynchronous validator variant.
 ///
 /// By default, any synchronous `Validator<T>` is also an `AsyncValidator<T>`
 /// via a blanket impl that wraps the synchronous call into a ready future.
+/// Its futures are boxed and not `Send`; see [`StaticAsyncValidator`] for
+/// validators whose futures need to be.
 #[cfg(feature = "async")]
-pub trait AsyncValidator<T> {
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot validate values of type `{T}` asynchronously",
+    label = "`{Self}` implements neither `AsyncValidator<{T}>` nor `StaticAsyncValidator<{T}>`"
+)]
+pub trait AsyncValidator<T: ?Sized> {
     /// Asynchronously validate the provided `value`.
     fn validate_async<'a>(&'a self, value: &'a T) -> Pin<Box<dyn Future<Output = Result<(), ValidationError>> + 'a>>;
 }
@@ -37,8 +149,59 @@ pub trait AsyncValidator<T> {
 impl<V, T> AsyncValidator<T> for V
 where
     V: Validator<T>,
+    T: ?Sized,
 {
     fn validate_async<'a>(&'a self, value: &'a T) -> Pin<Box<dyn Future<Output = Result<(), ValidationError>> + 'a>> {
         Box::pin(async move { self.validate(value) })
     }
-}
\ No newline at end of file
+}
+
+/// An asynchronous [`ContextValidator`] for static dispatch, next to
+/// [`AsyncContextValidator`] (see [`StaticAsyncValidator`]).
+///
+/// Any `ContextValidator<T, C>` (and thereby any `Validator<T>`) is also a
+/// `StaticAsyncContextValidator<T, C>` via a blanket impl.
+#[cfg(feature = "async")]
+pub trait StaticAsyncContextValidator<T: ?Sized, C: ?Sized> {
+    /// Asynchronously validate the provided `value` with the context `ctx`.
+    fn validate_with_static<'a>(&'a self, value: &'a T, ctx: &'a C) -> impl Future<Output = Result<(), ValidationError>> + 'a;
+}
+
+#[cfg(feature = "async")]
+impl<V, T, C> StaticAsyncContextValidator<T, C> for V
+where
+    V: ContextValidator<T, C>,
+    T: ?Sized,
+    C: ?Sized,
+{
+    async fn validate_with_static(&self, value: &T, ctx: &C) -> Result<(), ValidationError> {
+        self.validate_with(value, ctx)
+    }
+}
+
+/// An asynchronous [`ContextValidator`], e.g. for uniqueness checks against a
+/// DB handle passed as context.
+///
+/// Any `ContextValidator<T, C>` (and thereby any `Validator<T>`) is also an
+/// `AsyncContextValidator<T, C>` via a blanket impl.
+#[cfg(feature = "async")]
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot validate values of type `{T}` with context `{C}` asynchronously",
+    label = "`{Self}` implements neither `AsyncContextValidator<{T}, {C}>` nor `StaticAsyncContextValidator<{T}, {C}>`"
+)]
+pub trait AsyncContextValidator<T: ?Sized, C: ?Sized> {
+    /// Asynchronously validate the provided `value` with the context `ctx`.
+    fn validate_with_async<'a>(&'a self, value: &'a T, ctx: &'a C) -> Pin<Box<dyn Future<Output = Result<(), ValidationError>> + 'a>>;
+}
+
+#[cfg(feature = "async")]
+impl<V, T, C> AsyncContextValidator<T, C> for V
+where
+    V: ContextValidator<T, C>,
+    T: ?Sized,
+    C: ?Sized,
+{
+    fn validate_with_async<'a>(&'a self, value: &'a T, ctx: &'a C) -> Pin<Box<dyn Future<Output = Result<(), ValidationError>> + 'a>> {
+        Box::pin(async move { self.validate_with(value, ctx) })
+    }
+}
@@ -0,0 +1,408 @@
+//! JSON Schema export of the derived rules, e.g. to share them with client-side
+//! form validation.
+//!
+//! With the `json_schema` feature, `#[derive(Validate)]` also implements
+//! [`JsonSchema`]. The schema of a struct is an object schema with a property
+//! for every annotated field; fields that are not an `Option` are `required`.
+//! The type of a field contributes its own schema (`"type": "string"`,
+//! `"items"` of a `Vec`, the schema of a nested struct, `null` in the `type` of
+//! an `Option`, ...) and every validator of the annotation its keywords:
+//!
+//! | validator          | keywords                                           |
+//! |--------------------|----------------------------------------------------|
+//! | `MinLength`        | `minLength` (`minItems` for arrays)                |
+//! | `MaxLength`        | `maxLength` (`maxItems` for arrays)                |
+//! | `Length`           | both of the above                                  |
+//! | `Email`            | `format: email`                                    |
+//! | `Positive`         | `exclusiveMinimum: 0`                              |
+//! | `Negative`         | `exclusiveMaximum: 0`                              |
+//! | `NonZero`          | `not: { const: 0 }`                                |
+//! | `Range`            | `minimum`, `maximum`, `exclusiveMinimum`, ...      |
+//! | `NotAllowedChars`  | `not: { pattern }`                                 |
+//! | `any(...)`         | `anyOf`                                            |
+//! | `all(...)`         | the keywords of all validators                     |
+//! | `not(...)`         | `not`                                              |
+//!
+//! `minLength`/`maxLength` count characters, so they only describe length
+//! validators with `mode = LengthMode::Chars`; in the other modes (bytes by
+//! default) the rule is annotated with its mode instead, e.g. `{ "name":
+//! "MaxLength", "limit": 50, "mode": "bytes" }`.
+//!
+//! `each(...)` rules describe the `items` of arrays (`additionalProperties` of
+//! maps) and the value of an `Option`. Validators without a [`JsonSchemaRule`]
+//! impl, custom functions and cross-field rules are listed in the
+//! `x-validator` annotation instead (`[{ "name": "Slug" }, { "name":
+//! "custom", "function": "check_sku" }, { "name": "MustMatch", "other":
+//! "password" }]`). A type reached again inside its own schema, like `Chain`
+//! from `next: Option<Box<Chain>>`, is described once in the `$defs` of the
+//! outermost schema and referenced as `{ "$ref": "#/$defs/Chain" }`.
+//!
+//! Example
+//! ```
+//! use serde_json::json;
+//! use validator::json_schema::JsonSchema;
+//! use validator::validators::{Email, LengthMode, MaxLength, MinLength, Positive};
+//!
+//! #[derive(validator::Validate)]
+//! struct Signup {
+//!     #[validate(Email, MaxLength(50, mode = LengthMode::Chars))]
+//!     email: String,
+//!     #[validate(each(Positive))]
+//!     age: Option<u8>,
+//!     #[validate(each(MinLength(2, mode = LengthMode::Chars)))]
+//!     tags: Vec<String>,
+//! }
+//!
+//! assert_eq!(Signup::json_schema(), json!({
+//!     "type": "object",
+//!     "properties": {
+//!         "email": { "type": "string", "format": "email", "maxLength": 50 },
+//!         "age": { "type": ["integer", "null"], "exclusiveMinimum": 0 },
+//!         "tags": { "type": "array", "items": { "type": "string", "minLength": 2 } },
+//!     },
+//!     "required": ["email", "tags"],
+//! }));
+//! ```
+use serde_json::{Map, Value, json};
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::rc::Rc;
+use std::sync::Arc;
+
+/// Types describing their JSON representation as JSON Schema.
+///
+/// Implemented by `#[derive(Validate)]` with the `json_schema` feature and for
+/// the standard types (strings, numbers, `bool`, collections, `Option`, ...).
+pub trait JsonSchema {
+    fn json_schema() -> Value;
+}
+
+/// Validators describing the values they accept as JSON Schema keywords.
+///
+/// `schema` is the schema of the validated field so far, e.g.
+/// `{ "type": "string" }`; add (or adjust) keywords for the validator. Use the
+/// `type` keyword to tell strings and arrays apart where the keywords differ;
+/// the `type` of an `Option` also lists `null`, e.g. `["string", "null"]`.
+///
+/// Example
+/// ```
+/// use serde_json::{json, Map, Value};
+/// use validator::json_schema::JsonSchemaRule;
+///
+/// struct Slug;
+///
+/// impl JsonSchemaRule for Slug {
+///     fn extend_schema(&self, schema: &mut Map<String, Value>) {
+///         schema.insert("pattern".into(), json!("^[a-z-]+$"));
+///     }
+/// }
+/// ```
+pub trait JsonSchemaRule {
+    fn extend_schema(&self, schema: &mut Map<String, Value>);
+}
+
+impl<V: JsonSchemaRule + ?Sized> JsonSchemaRule for &V {
+    fn extend_schema(&self, schema: &mut Map<String, Value>) { (**self).extend_schema(schema) }
+}
+
+/// Name of the keyword bounding the length of values with the `type` of
+/// `schema`: `minItems`/`maxItems` for arrays, `minProperties`/`maxProperties`
+/// for objects and `minLength`/`maxLength` for strings. These count characters,
+/// so strings measured in another `mode` get none and the rule goes to the
+/// `x-validator` annotation with its mode instead.
+#[cfg(any(feature = "length", feature = "max_length", feature = "min_length"))]
+pub(crate) fn length_keyword(schema: &Map<String, Value>, mode: crate::validators::LengthMode, max: bool) -> Option<&'static str> {
+    match (schema_type(schema), max) {
+        (Some("array"), false) => Some("minItems"),
+        (Some("array"), true) => Some("maxItems"),
+        (Some("object"), false) => Some("minProperties"),
+        (Some("object"), true) => Some("maxProperties"),
+        _ if mode != crate::validators::LengthMode::Chars => None,
+        (_, false) => Some("minLength"),
+        (_, true) => Some("maxLength"),
+    }
+}
+
+/// The `type` of `schema` besides `null`, e.g. `string` for the schema of an
+/// `Option<String>`, `{ "type": ["string", "null"] }`.
+pub(crate) fn schema_type(schema: &Map<String, Value>) -> Option<&str> {
+    match schema.get("type")? {
+        Value::String(ty) => Some(ty),
+        Value::Array(types) => types.iter().filter_map(Value::as_str).find(|ty| *ty != "null"),
+        _ => None,
+    }
+}
+
+/// `schema` also accepting `null`: with `null` added to its `type`, or as
+/// `anyOf` the schema and `null` when it has none.
+fn nullable(schema: Value) -> Value {
+    let Value::Object(mut schema) = schema else { return schema };
+    if !schema.contains_key("type") {
+        return if schema.is_empty() { Value::Object(schema) } else { json!({ "anyOf": [schema, { "type": "null" }] }) };
+    }
+    match schema.get_mut("type") {
+        Some(ty @ Value::String(_)) => *ty = json!([ty.take(), "null"]),
+        Some(Value::Array(types)) if !types.iter().any(|ty| ty == "null") => types.push("null".into()),
+        _ => {}
+    }
+    Value::Object(schema)
+}
+
+/// A number as JSON value, `null` for `NaN` and infinities.
+#[cfg(feature = "range")]
+pub(crate) fn json_number(value: &impl std::fmt::Display) -> Value {
+    value.to_string().parse::<serde_json::Number>().map_or(Value::Null, Value::Number)
+}
+
+/// The keywords `rule` adds to a schema of the type of `schema`, without that
+/// type.
+pub(crate) fn fragment(rule: &(impl JsonSchemaRule + ?Sized), schema: &Map<String, Value>) -> Value {
+    let mut fragment = Map::new();
+    if let Some(ty) = schema.get("type") {
+        fragment.insert("type".into(), ty.clone());
+    }
+    rule.extend_schema(&mut fragment);
+    fragment.remove("type");
+    Value::Object(fragment)
+}
+
+macro_rules! impl_json_schema {
+    ($($schema:tt for $($ty:ty),+;)*) => {
+        $($(impl JsonSchema for $ty {
+            fn json_schema() -> Value { json!($schema) }
+        })+)*
+    };
+}
+
+impl_json_schema! {
+    { "type": "string" } for str, String, Cow<'_, str>, char;
+    { "type": "integer" } for i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize;
+    { "type": "number" } for f32, f64;
+    { "type": "boolean" } for bool;
+}
+
+macro_rules! impl_json_schema_for_wrapper {
+    ($($ty:ty),*) => {
+        $(impl<T: JsonSchema + ?Sized> JsonSchema for $ty {
+            fn json_schema() -> Value { T::json_schema() }
+        })*
+    };
+}
+
+impl_json_schema_for_wrapper!(&T, Box<T>, Rc<T>, Arc<T>);
+
+impl<T: JsonSchema> JsonSchema for Option<T> {
+    fn json_schema() -> Value { nullable(T::json_schema()) }
+}
+
+macro_rules! impl_json_schema_for_list {
+    ($([$($generics:tt)*] $ty:ty),* $(,)?) => {
+        $(impl<$($generics)*> JsonSchema for $ty {
+            fn json_schema() -> Value { json!({ "type": "array", "items": T::json_schema() }) }
+        })*
+    };
+}
+
+impl_json_schema_for_list! {
+    [T: JsonSchema] [T],
+    [T: JsonSchema, const N: usize] [T; N],
+    [T: JsonSchema] Vec<T>,
+    [T: JsonSchema] VecDeque<T>,
+    [T: JsonSchema, S] HashSet<T, S>,
+    [T: JsonSchema] BTreeSet<T>,
+}
+
+impl<K, V: JsonSchema, S> JsonSchema for HashMap<K, V, S> {
+    fn json_schema() -> Value { json!({ "type": "object", "additionalProperties": V::json_schema() }) }
+}
+
+impl<K, V: JsonSchema> JsonSchema for BTreeMap<K, V> {
+    fn json_schema() -> Value { json!({ "type": "object", "additionalProperties": V::json_schema() }) }
+}
+
+/// Helpers of the derived `JsonSchema` impls, not part of the public API.
+///
+/// Field types without a `JsonSchema` impl fall back to an empty schema and
+/// validators without a `JsonSchemaRule` impl to an `x-validator` annotation
+/// with their name: the derive calls the probe methods on a
+/// reference, which picks the impl for the probe itself when the bound holds
+/// and the one for the reference to it otherwise.
+#[doc(hidden)]
+pub mod __private {
+    use super::{JsonSchema, JsonSchemaRule};
+    use core::marker::PhantomData;
+    use serde_json::{Map, Value};
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+
+    pub use serde_json;
+
+    /// The derived schemas being built on this thread, to refer to a type
+    /// reached again inside its own schema instead of recursing forever.
+    #[derive(Default)]
+    struct Definitions {
+        /// `type_name`s of the types being built, outermost first.
+        building: Vec<&'static str>,
+        /// `$defs` names of the types being built and of the recursive ones.
+        names: HashMap<&'static str, String>,
+        /// Types reached again while building their schema.
+        recursive: Vec<&'static str>,
+        /// Schemas of the recursive types, added to the outermost schema.
+        defs: Map<String, Value>,
+    }
+
+    thread_local! {
+        static DEFINITIONS: RefCell<Definitions> = RefCell::default();
+    }
+
+    fn reference(name: &str) -> Value { serde_json::json!({ "$ref": format!("#/$defs/{name}") }) }
+
+    /// The schema `build` returns for `T`, as `{ "$ref": "#/$defs/<name>" }`
+    /// with the schema in `$defs` when `T` is reached again while building it,
+    /// e.g. `Chain` from `next: Option<Box<Chain>>`. The `$defs` go to the
+    /// outermost schema; `name` gets a number when another type took it.
+    pub fn definition<T: ?Sized>(name: &str, build: impl FnOnce() -> Value) -> Value {
+        let key = core::any::type_name::<T>();
+        let entered = DEFINITIONS.with_borrow_mut(|defs| {
+            if let Some(name) = defs.names.get(key) {
+                if defs.building.contains(&key) && !defs.recursive.contains(&key) {
+                    defs.recursive.push(key);
+                }
+                return Err(reference(name));
+            }
+            let mut unique = name.to_string();
+            for n in 2.. {
+                if !defs.names.values().any(|taken| *taken == unique) {
+                    break;
+                }
+                unique = format!("{name}{n}");
+            }
+            defs.names.insert(key, unique);
+            defs.building.push(key);
+            Ok(())
+        });
+        if let Err(reference) = entered {
+            return reference;
+        }
+        let schema = build();
+        DEFINITIONS.with_borrow_mut(|defs| {
+            defs.building.pop();
+            let mut schema = match defs.recursive.iter().position(|recursive| *recursive == key) {
+                Some(index) => {
+                    defs.recursive.remove(index);
+                    let name = defs.names[key].clone();
+                    defs.defs.insert(name.clone(), schema);
+                    reference(&name)
+                }
+                None => {
+                    defs.names.remove(key);
+                    schema
+                }
+            };
+            if defs.building.is_empty() {
+                defs.names.clear();
+                let found = core::mem::take(&mut defs.defs);
+                if let (Value::Object(schema), false) = (&mut schema, found.is_empty()) {
+                    schema.insert("$defs".into(), Value::Object(found));
+                }
+            }
+            schema
+        })
+    }
+
+    pub struct TypeProbe<T: ?Sized>(PhantomData<fn() -> Box<T>>);
+
+    impl<T: ?Sized> TypeProbe<T> {
+        #[allow(clippy::new_without_default)]
+        pub fn new() -> Self { TypeProbe(PhantomData) }
+    }
+
+    pub trait ViaJsonSchema {
+        fn type_schema(&self) -> Map<String, Value>;
+    }
+
+    impl<T: JsonSchema + ?Sized> ViaJsonSchema for TypeProbe<T> {
+        fn type_schema(&self) -> Map<String, Value> {
+            match T::json_schema() {
+                Value::Object(schema) => schema,
+                _ => Map::new(),
+            }
+        }
+    }
+
+    pub trait ViaAnyType {
+        fn type_schema(&self) -> Map<String, Value>;
+    }
+
+    impl<T: ?Sized> ViaAnyType for &TypeProbe<T> {
+        fn type_schema(&self) -> Map<String, Value> { Map::new() }
+    }
+
+    pub struct RuleProbe<'a, V>(pub &'a V, pub &'static str);
+
+    pub trait ViaJsonSchemaRule {
+        fn rule_schema(&self, schema: &mut Map<String, Value>);
+    }
+
+    impl<V: JsonSchemaRule> ViaJsonSchemaRule for RuleProbe<'_, V> {
+        fn rule_schema(&self, schema: &mut Map<String, Value>) { self.0.extend_schema(schema) }
+    }
+
+    pub trait ViaAnyRule {
+        fn rule_schema(&self, schema: &mut Map<String, Value>);
+    }
+
+    impl<V> ViaAnyRule for &RuleProbe<'_, V> {
+        fn rule_schema(&self, schema: &mut Map<String, Value>) {
+            let mut rule = Map::new();
+            rule.insert("name".into(), self.1.into());
+            annotate(schema, Value::Object(rule));
+        }
+    }
+
+    /// Record a rule the schema keywords cannot express in the `x-validator`
+    /// annotation, e.g. `{ "name": "custom", "function": "check_sku" }`.
+    pub fn annotate(schema: &mut Map<String, Value>, rule: Value) {
+        if let Value::Array(rules) = schema.entry("x-validator").or_insert_with(|| Value::Array(Vec::new())) {
+            rules.push(rule);
+        }
+    }
+
+    /// Run `rules` on the schema of the items of the collection described by
+    /// `schema`: `additionalProperties` of objects, `items` otherwise.
+    pub fn with_items(schema: &mut Map<String, Value>, rules: impl FnOnce(&mut Map<String, Value>)) {
+        let key = if super::schema_type(schema) == Some("object") { "additionalProperties" } else { "items" };
+        let mut items = match schema.remove(key) {
+            Some(Value::Object(items)) => items,
+            _ => Map::new(),
+        };
+        rules(&mut items);
+        schema.insert(key.into(), Value::Object(items));
+    }
+
+    /// Schema of one of the given variants.
+    pub fn one_of(variants: Vec<Value>) -> Value {
+        let mut schema = Map::new();
+        schema.insert("oneOf".into(), variants.into());
+        Value::Object(schema)
+    }
+
+    /// Array schema with the given items, for tuples.
+    pub fn tuple(items: Vec<Value>) -> Value {
+        let mut schema = Map::new();
+        schema.insert("type".into(), "array".into());
+        schema.insert("prefixItems".into(), items.into());
+        Value::Object(schema)
+    }
+
+    /// Object schema with the given properties, `required` when not empty.
+    pub fn object(properties: Map<String, Value>, required: Vec<&str>) -> Value {
+        let mut schema = Map::new();
+        schema.insert("type".into(), "object".into());
+        schema.insert("properties".into(), Value::Object(properties));
+        if !required.is_empty() {
+            schema.insert("required".into(), required.into());
+        }
+        Value::Object(schema)
+    }
+}
@@ -1,5 +1,13 @@
 pub mod validators;
 pub mod validate;
+pub mod collection;
+pub mod rules;
+#[cfg(feature = "dynamic")]
+pub mod dynamic;
+#[cfg(feature = "json_schema")]
+pub mod json_schema;
+#[cfg(feature = "async")]
+pub mod concurrency;
 pub mod prelude;
 mod engine;
 
@@ -10,6 +18,413 @@ pub use validator_derive::Validate;
 #[cfg(feature = "async")]
 pub use futures;
 
+/// Helpers of the derived impls, not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    use crate::engine::error::ValidationError;
+    use crate::engine::validator::{ContextValidator, CrossFieldValidator, Validator};
+    use crate::validate::{Validate, ValidateWith};
+    use core::marker::PhantomData;
+    #[cfg(feature = "async")]
+    use crate::engine::errors::ValidationErrors;
+    #[cfg(feature = "async")]
+    use crate::engine::state::ValidationState;
+    #[cfg(feature = "async")]
+    use crate::engine::validator::{AsyncContextValidator, AsyncValidator, StaticAsyncContextValidator, StaticAsyncValidator};
+    #[cfg(feature = "async")]
+    use crate::validate::{StaticValidateAsync, StaticValidateWithAsync, ValidateAsync, ValidateWithAsync};
+    #[cfg(feature = "async")]
+    use std::future::Future;
+
+    /// Probe of the validators of the derive for values of type `T`, like
+    /// `rules::__private::DescribeProbe`. `(&&&&SyncProbe(..)).sync_validator()`
+    /// yields the validator to call in `Validate`, or [`AsyncOnly`] for
+    /// validators implementing only `AsyncValidator` or `StaticAsyncValidator`,
+    /// which are only run by `ValidateAsync`, like async custom functions.
+    /// Called without validating, the probe pins the type parameters of
+    /// validators that are only inferred from the validated value (e.g.
+    /// `NotDefault<T>: Validator<T>`), as for JSON Schema and rule descriptors.
+    pub struct SyncProbe<'a, V: ?Sized, T: ?Sized>(pub &'a V, pub PhantomData<fn(&T)>);
+
+    impl<'a, V: ?Sized, T: ?Sized> SyncProbe<'a, V, T> {
+        /// Probe of `v` for the type of `value`
+        pub fn of(v: &'a V, _value: &T) -> Self { SyncProbe(v, PhantomData) }
+    }
+
+    pub trait ViaValidator<'a, V: ?Sized> {
+        fn sync_validator(&self) -> &'a V;
+    }
+
+    impl<'a, V: Validator<T> + ?Sized, T: ?Sized> ViaValidator<'a, V> for &&&SyncProbe<'a, V, T> {
+        fn sync_validator(&self) -> &'a V { self.0 }
+    }
+
+    #[cfg(feature = "async")]
+    pub trait ViaAsyncOnly {
+        fn sync_validator(&self) -> AsyncOnly;
+    }
+
+    #[cfg(feature = "async")]
+    impl<V: AsyncValidator<T> + ?Sized, T: ?Sized> ViaAsyncOnly for &&SyncProbe<'_, V, T> {
+        fn sync_validator(&self) -> AsyncOnly { AsyncOnly }
+    }
+
+    #[cfg(feature = "async")]
+    pub trait ViaStaticAsyncOnly {
+        fn sync_validator(&self) -> AsyncOnly;
+    }
+
+    #[cfg(feature = "async")]
+    impl<V: StaticAsyncValidator<T> + ?Sized, T: ?Sized> ViaStaticAsyncOnly for &SyncProbe<'_, V, T> {
+        fn sync_validator(&self) -> AsyncOnly { AsyncOnly }
+    }
+
+    /// Last resort, leaving the unsatisfied `Validator` bound to the call.
+    pub trait ViaAnyValidator<'a, V: ?Sized> {
+        fn sync_validator(&self) -> &'a V;
+    }
+
+    impl<'a, V: ?Sized, T: ?Sized> ViaAnyValidator<'a, V> for SyncProbe<'a, V, T> {
+        fn sync_validator(&self) -> &'a V { self.0 }
+    }
+
+    /// [`SyncProbe`] of validators with the context `C`.
+    pub struct ContextSyncProbe<'a, V: ?Sized, T: ?Sized, C: ?Sized>(pub &'a V, pub PhantomData<fn(&T, &C)>);
+
+    impl<'a, V: ?Sized, T: ?Sized, C: ?Sized> ContextSyncProbe<'a, V, T, C> {
+        /// Probe of `v` for the types of `value` and `ctx`
+        pub fn of(v: &'a V, _value: &T, _ctx: &C) -> Self { ContextSyncProbe(v, PhantomData) }
+    }
+
+    pub trait ViaContextValidator<'a, V: ?Sized> {
+        fn sync_validator(&self) -> &'a V;
+    }
+
+    impl<'a, V: ContextValidator<T, C> + ?Sized, T: ?Sized, C: ?Sized> ViaContextValidator<'a, V> for &&&ContextSyncProbe<'a, V, T, C> {
+        fn sync_validator(&self) -> &'a V { self.0 }
+    }
+
+    #[cfg(feature = "async")]
+    pub trait ViaAsyncContextOnly {
+        fn sync_validator(&self) -> AsyncOnly;
+    }
+
+    #[cfg(feature = "async")]
+    impl<V: AsyncContextValidator<T, C> + ?Sized, T: ?Sized, C: ?Sized> ViaAsyncContextOnly for &&ContextSyncProbe<'_, V, T, C> {
+        fn sync_validator(&self) -> AsyncOnly { AsyncOnly }
+    }
+
+    #[cfg(feature = "async")]
+    pub trait ViaStaticAsyncContextOnly {
+        fn sync_validator(&self) -> AsyncOnly;
+    }
+
+    #[cfg(feature = "async")]
+    impl<V: StaticAsyncContextValidator<T, C> + ?Sized, T: ?Sized, C: ?Sized> ViaStaticAsyncContextOnly for &ContextSyncProbe<'_, V, T, C> {
+        fn sync_validator(&self) -> AsyncOnly { AsyncOnly }
+    }
+
+    /// Last resort, leaving the unsatisfied `ContextValidator` bound to the call.
+    pub trait ViaAnyContextValidator<'a, V: ?Sized> {
+        fn sync_validator(&self) -> &'a V;
+    }
+
+    impl<'a, V: ?Sized, T: ?Sized, C: ?Sized> ViaAnyContextValidator<'a, V> for ContextSyncProbe<'a, V, T, C> {
+        fn sync_validator(&self) -> &'a V { self.0 }
+    }
+
+    /// Stand-in for async-only validators in `Validate`, passing every value.
+    pub struct AsyncOnly;
+
+    impl AsyncOnly {
+        pub fn validate<T: ?Sized>(&self, _value: &T) -> Result<(), ValidationError> { Ok(()) }
+        pub fn validate_with<T: ?Sized, C: ?Sized>(&self, _value: &T, _ctx: &C) -> Result<(), ValidationError> { Ok(()) }
+    }
+
+    /// [`SyncProbe`] of `nested` values: `(&&&&NestedSyncProbe::of(..)).sync_nested()`
+    /// yields the value to validate in `Validate`, or [`AsyncOnlyNested`] for
+    /// values implementing only `ValidateAsync` or `StaticValidateAsync`.
+    pub struct NestedSyncProbe<'a, T: ?Sized, C: ?Sized>(pub &'a T, pub PhantomData<fn(&C)>);
+
+    impl<'a, T: ?Sized> NestedSyncProbe<'a, T, ()> {
+        /// Probe of `value` for `Validate`
+        pub fn of(value: &'a T) -> Self { NestedSyncProbe(value, PhantomData) }
+    }
+
+    impl<'a, T: ?Sized, C: ?Sized> NestedSyncProbe<'a, T, C> {
+        /// Probe of `value` for `ValidateWith<C>`
+        pub fn with(value: &'a T, _ctx: &C) -> Self { NestedSyncProbe(value, PhantomData) }
+    }
+
+    pub trait ViaValidate<'a, T: ?Sized> {
+        fn sync_nested(&self) -> &'a T;
+    }
+
+    impl<'a, T: Validate + ?Sized> ViaValidate<'a, T> for &&&NestedSyncProbe<'a, T, ()> {
+        fn sync_nested(&self) -> &'a T { self.0 }
+    }
+
+    #[cfg(feature = "async")]
+    pub trait ViaValidateAsyncOnly {
+        fn sync_nested(&self) -> &'static AsyncOnlyNested;
+    }
+
+    #[cfg(feature = "async")]
+    impl<T: ValidateAsync + ?Sized> ViaValidateAsyncOnly for &&NestedSyncProbe<'_, T, ()> {
+        fn sync_nested(&self) -> &'static AsyncOnlyNested { &AsyncOnlyNested }
+    }
+
+    #[cfg(feature = "async")]
+    pub trait ViaStaticValidateAsyncOnly {
+        fn sync_nested(&self) -> &'static AsyncOnlyNested;
+    }
+
+    #[cfg(feature = "async")]
+    impl<T: StaticValidateAsync + ?Sized> ViaStaticValidateAsyncOnly for &NestedSyncProbe<'_, T, ()> {
+        fn sync_nested(&self) -> &'static AsyncOnlyNested { &AsyncOnlyNested }
+    }
+
+    pub trait ViaValidateWith<'a, T: ?Sized> {
+        fn sync_nested(&self) -> &'a T;
+    }
+
+    impl<'a, T: ValidateWith<C> + ?Sized, C: ?Sized> ViaValidateWith<'a, T> for &&&NestedSyncProbe<'a, T, C> {
+        fn sync_nested(&self) -> &'a T { self.0 }
+    }
+
+    #[cfg(feature = "async")]
+    pub trait ViaValidateWithAsyncOnly {
+        fn sync_nested(&self) -> &'static AsyncOnlyNested;
+    }
+
+    #[cfg(feature = "async")]
+    impl<T: ValidateWithAsync<C> + ?Sized, C: ?Sized> ViaValidateWithAsyncOnly for &&NestedSyncProbe<'_, T, C> {
+        fn sync_nested(&self) -> &'static AsyncOnlyNested { &AsyncOnlyNested }
+    }
+
+    #[cfg(feature = "async")]
+    pub trait ViaStaticValidateWithAsyncOnly {
+        fn sync_nested(&self) -> &'static AsyncOnlyNested;
+    }
+
+    #[cfg(feature = "async")]
+    impl<T: StaticValidateWithAsync<C> + ?Sized, C: ?Sized> ViaStaticValidateWithAsyncOnly for &NestedSyncProbe<'_, T, C> {
+        fn sync_nested(&self) -> &'static AsyncOnlyNested { &AsyncOnlyNested }
+    }
+
+    /// Last resort, leaving the unsatisfied `Validate` (or `ValidateWith`)
+    /// bound to the call.
+    pub trait ViaAnyNested<'a, T: ?Sized> {
+        fn sync_nested(&self) -> &'a T;
+    }
+
+    impl<'a, T: ?Sized, C: ?Sized> ViaAnyNested<'a, T> for NestedSyncProbe<'a, T, C> {
+        fn sync_nested(&self) -> &'a T { self.0 }
+    }
+
+    /// Stand-in for async-only `nested` values in `Validate`, without errors.
+    pub struct AsyncOnlyNested;
+
+    impl Validate for AsyncOnlyNested {
+        fn validate(&self) -> Result<(), ValidationError> { Ok(()) }
+    }
+
+    impl<C: ?Sized> ValidateWith<C> for AsyncOnlyNested {
+        fn validate_with(&self, _ctx: &C) -> Result<(), ValidationError> { Ok(()) }
+    }
+
+    /// Pins the type parameters of cross-field validators comparing a `T`
+    /// with a `U` (see [`SyncProbe`]).
+    pub fn cross_field_validator_for<T: ?Sized, U: ?Sized, V: CrossFieldValidator<T, U>>(v: V, _: PhantomData<fn(&T, &U)>) -> V { v }
+
+    /// `Validator::validate` for the method calls of the derive,
+    /// `(&v).validate(value)`. The calls resolve to `Validator` itself when
+    /// it is in scope (e.g. through `use validator::prelude::*`), and to this
+    /// impl for the reference, found one autoref later, otherwise.
+    pub trait ValidateFallback<V: ?Sized> {
+        fn validate<T: ?Sized>(&self, value: &T) -> Result<(), ValidationError>
+        where
+            V: Validator<T>;
+    }
+
+    impl<V: ?Sized> ValidateFallback<V> for &V {
+        fn validate<T: ?Sized>(&self, value: &T) -> Result<(), ValidationError>
+        where
+            V: Validator<T>,
+        {
+            Validator::validate(*self, value)
+        }
+    }
+
+    /// Probes of the async derive, picking the static traits over the boxed
+    /// ones like `rules::__private::DescribeProbe`: the derive calls the
+    /// method on a probe, which resolves to the impl for the probe itself when
+    /// the static trait is implemented and to the one for the reference to it,
+    /// requiring the boxed trait, otherwise. Hand-written `AsyncValidator`s and `ValidateAsync` types are
+    /// thereby accepted next to the static ones.
+    #[cfg(feature = "async")]
+    pub struct AsyncProbe<'a, V: ?Sized, T: ?Sized>(pub &'a V, pub &'a T);
+
+    #[cfg(feature = "async")]
+    pub trait ViaStaticAsyncValidator<'a> {
+        fn validate_probe(&self) -> impl Future<Output = Result<(), ValidationError>> + 'a;
+    }
+
+    #[cfg(feature = "async")]
+    impl<'a, V: StaticAsyncValidator<T> + ?Sized, T: ?Sized> ViaStaticAsyncValidator<'a> for AsyncProbe<'a, V, T> {
+        fn validate_probe(&self) -> impl Future<Output = Result<(), ValidationError>> + 'a { self.0.validate_static(self.1) }
+    }
+
+    /// Last level of [`AsyncProbe`], leaving an unsatisfied `AsyncValidator`
+    /// bound to the call.
+    #[cfg(feature = "async")]
+    pub trait ViaAsyncValidator<'a, V: ?Sized, T: ?Sized> {
+        fn validate_probe(&self) -> impl Future<Output = Result<(), ValidationError>> + 'a
+        where
+            V: AsyncValidator<T>;
+    }
+
+    #[cfg(feature = "async")]
+    impl<'a, V: ?Sized, T: ?Sized> ViaAsyncValidator<'a, V, T> for &AsyncProbe<'a, V, T> {
+        fn validate_probe(&self) -> impl Future<Output = Result<(), ValidationError>> + 'a
+        where
+            V: AsyncValidator<T>,
+        {
+            self.0.validate_async(self.1)
+        }
+    }
+
+    /// [`AsyncProbe`] of validators with the context `C`.
+    #[cfg(feature = "async")]
+    pub struct AsyncContextProbe<'a, V: ?Sized, T: ?Sized, C: ?Sized>(pub &'a V, pub &'a T, pub &'a C);
+
+    #[cfg(feature = "async")]
+    pub trait ViaStaticAsyncContextValidator<'a> {
+        fn validate_with_probe(&self) -> impl Future<Output = Result<(), ValidationError>> + 'a;
+    }
+
+    #[cfg(feature = "async")]
+    impl<'a, V, T, C> ViaStaticAsyncContextValidator<'a> for AsyncContextProbe<'a, V, T, C>
+    where
+        V: StaticAsyncContextValidator<T, C> + ?Sized,
+        T: ?Sized,
+        C: ?Sized,
+    {
+        fn validate_with_probe(&self) -> impl Future<Output = Result<(), ValidationError>> + 'a {
+            self.0.validate_with_static(self.1, self.2)
+        }
+    }
+
+    #[cfg(feature = "async")]
+    pub trait ViaAsyncContextValidator<'a, V: ?Sized, T: ?Sized, C: ?Sized> {
+        fn validate_with_probe(&self) -> impl Future<Output = Result<(), ValidationError>> + 'a
+        where
+            V: AsyncContextValidator<T, C>;
+    }
+
+    #[cfg(feature = "async")]
+    impl<'a, V: ?Sized, T: ?Sized, C: ?Sized> ViaAsyncContextValidator<'a, V, T, C> for &AsyncContextProbe<'a, V, T, C> {
+        fn validate_with_probe(&self) -> impl Future<Output = Result<(), ValidationError>> + 'a
+        where
+            V: AsyncContextValidator<T, C>,
+        {
+            self.0.validate_with_async(self.1, self.2)
+        }
+    }
+
+    /// [`AsyncProbe`] of `nested` values.
+    #[cfg(feature = "async")]
+    pub struct NestedProbe<'a, T: ?Sized>(pub &'a T);
+
+    #[cfg(feature = "async")]
+    pub trait ViaStaticValidateAsync<'a> {
+        fn validate_nested(&self, state: &'a mut ValidationState) -> impl Future<Output = Result<(), ValidationError>> + 'a;
+        fn complete_validate_nested(&self, state: &'a mut ValidationState) -> impl Future<Output = Result<(), ValidationErrors>> + 'a;
+    }
+
+    #[cfg(feature = "async")]
+    impl<'a, T: StaticValidateAsync + ?Sized> ViaStaticValidateAsync<'a> for NestedProbe<'a, T> {
+        fn validate_nested(&self, state: &'a mut ValidationState) -> impl Future<Output = Result<(), ValidationError>> + 'a {
+            self.0.validate_static_in(state)
+        }
+        fn complete_validate_nested(&self, state: &'a mut ValidationState) -> impl Future<Output = Result<(), ValidationErrors>> + 'a {
+            self.0.complete_validate_static_in(state)
+        }
+    }
+
+    #[cfg(feature = "async")]
+    pub trait ViaValidateAsync<'a, T: ?Sized> {
+        fn validate_nested(&self, state: &'a mut ValidationState) -> impl Future<Output = Result<(), ValidationError>> + 'a
+        where
+            T: ValidateAsync;
+        fn complete_validate_nested(&self, state: &'a mut ValidationState) -> impl Future<Output = Result<(), ValidationErrors>> + 'a
+        where
+            T: ValidateAsync;
+    }
+
+    #[cfg(feature = "async")]
+    impl<'a, T: ?Sized> ViaValidateAsync<'a, T> for &NestedProbe<'a, T> {
+        fn validate_nested(&self, state: &'a mut ValidationState) -> impl Future<Output = Result<(), ValidationError>> + 'a
+        where
+            T: ValidateAsync,
+        {
+            self.0.validate_async_in(state)
+        }
+        fn complete_validate_nested(&self, state: &'a mut ValidationState) -> impl Future<Output = Result<(), ValidationErrors>> + 'a
+        where
+            T: ValidateAsync,
+        {
+            self.0.complete_validate_async_in(state)
+        }
+    }
+
+    /// [`NestedProbe`] of values validated with the context `C`.
+    #[cfg(feature = "async")]
+    pub struct NestedContextProbe<'a, T: ?Sized, C: ?Sized>(pub &'a T, pub &'a C);
+
+    #[cfg(feature = "async")]
+    pub trait ViaStaticValidateWithAsync<'a> {
+        fn validate_nested(&self, state: &'a mut ValidationState) -> impl Future<Output = Result<(), ValidationError>> + 'a;
+        fn complete_validate_nested(&self, state: &'a mut ValidationState) -> impl Future<Output = Result<(), ValidationErrors>> + 'a;
+    }
+
+    #[cfg(feature = "async")]
+    impl<'a, T: StaticValidateWithAsync<C> + ?Sized, C: ?Sized> ViaStaticValidateWithAsync<'a> for NestedContextProbe<'a, T, C> {
+        fn validate_nested(&self, state: &'a mut ValidationState) -> impl Future<Output = Result<(), ValidationError>> + 'a {
+            self.0.validate_with_static_in(self.1, state)
+        }
+        fn complete_validate_nested(&self, state: &'a mut ValidationState) -> impl Future<Output = Result<(), ValidationErrors>> + 'a {
+            self.0.complete_validate_with_static_in(self.1, state)
+        }
+    }
+
+    #[cfg(feature = "async")]
+    pub trait ViaValidateWithAsync<'a, T: ?Sized, C: ?Sized> {
+        fn validate_nested(&self, state: &'a mut ValidationState) -> impl Future<Output = Result<(), ValidationError>> + 'a
+        where
+            T: ValidateWithAsync<C>;
+        fn complete_validate_nested(&self, state: &'a mut ValidationState) -> impl Future<Output = Result<(), ValidationErrors>> + 'a
+        where
+            T: ValidateWithAsync<C>;
+    }
+
+    #[cfg(feature = "async")]
+    impl<'a, T: ?Sized, C: ?Sized> ViaValidateWithAsync<'a, T, C> for &NestedContextProbe<'a, T, C> {
+        fn validate_nested(&self, state: &'a mut ValidationState) -> impl Future<Output = Result<(), ValidationError>> + 'a
+        where
+            T: ValidateWithAsync<C>,
+        {
+            self.0.validate_with_async_in(self.1, state)
+        }
+        fn complete_validate_nested(&self, state: &'a mut ValidationState) -> impl Future<Output = Result<(), ValidationErrors>> + 'a
+        where
+            T: ValidateWithAsync<C>,
+        {
+            self.0.complete_validate_with_async_in(self.1, state)
+        }
+    }
+}
+
 pub fn print_check<T, V, FOK, FERR>(v: &V, value: &T, on_ok: FOK, on_err: FERR)
 where
     V: Validator<T>,
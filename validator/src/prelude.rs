@@ -1,7 +1,13 @@
 pub use crate::engine::error::ValidationError;
-pub use crate::engine::validator::Validator;
-pub use crate::validate::Validate;
+pub use crate::engine::errors::{PathSegment, ValidationErrors};
+pub use crate::engine::i18n::{MessageCatalog, MessageResolver};
+pub use crate::engine::options::ValidationOptions;
+pub use crate::engine::state::ValidationState;
+pub use crate::engine::message::render_message;
+pub use crate::engine::validator::{ContextValidator, CrossFieldValidator, Validator};
+pub use crate::validate::{Validate, ValidateWith};
+pub use crate::rules::{Describe, FieldRules, RuleDescriptor, ValidationRules};
 #[cfg(feature = "async")]
-pub use crate::engine::validator::AsyncValidator;
+pub use crate::engine::validator::{AsyncContextValidator, AsyncValidator, StaticAsyncContextValidator, StaticAsyncValidator};
 #[cfg(feature = "async")]
-pub use crate::validate::ValidateAsync;
\ No newline at end of file
+pub use crate::validate::{StaticValidateAsync, StaticValidateWithAsync, ValidateAsync, ValidateWithAsync};
\ No newline at end of file
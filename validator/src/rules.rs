@@ -0,0 +1,131 @@
+//! Introspection of the rules of derived types, e.g. to render help text next
+//! to form fields or to document an API.
+//!
+//! `#[derive(Validate)]` implements [`ValidationRules`], listing the rules of
+//! every annotated field in declaration order. Validators describe themselves
+//! through [`Describe`]; validators without an impl are listed with the name
+//! given in the annotation only.
+//!
+//! Example
+//! ```
+//! use validator::prelude::*;
+//! use validator::validators::{MaxLength, NotAllowedChars};
+//!
+//! #[derive(validator::Validate)]
+//! struct Comment {
+//!     #[validate(MaxLength(280), NotAllowedChars(["<", ">"]))]
+//!     text: String,
+//! }
+//!
+//! let rules = Comment::validation_rules();
+//! assert_eq!(rules[0].field, "text");
+//! let max = &rules[0].rules[0];
+//! assert_eq!((max.name.as_ref(), max.code.as_ref()), ("MaxLength", "max_length"));
+//! assert_eq!(max.params["limit"], "280");
+//! assert_eq!(rules[0].rules[1].params["forbidden"], "<, >");
+//! ```
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+/// What a single rule checks.
+///
+/// - name: the validator (`MaxLength`) or keyword (`each`, `nested`, `custom`,
+///   `schema`)
+/// - code: the code of the errors the rule reports, empty when unknown
+/// - params: the settings of the rule, rendered like error params (`limit`,
+///   `min`, `forbidden`, `other`, ...)
+/// - message: the message template given to the rule, if any
+/// - rules: inner rules of `each(...)` and of combinators like `any(...)`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RuleDescriptor {
+    pub name: Cow<'static, str>,
+    pub code: Cow<'static, str>,
+    pub params: BTreeMap<Cow<'static, str>, Cow<'static, str>>,
+    pub message: Option<Cow<'static, str>>,
+    pub rules: Vec<RuleDescriptor>,
+}
+
+impl RuleDescriptor {
+    pub fn new(name: impl Into<Cow<'static, str>>, code: impl Into<Cow<'static, str>>) -> Self {
+        Self { name: name.into(), code: code.into(), ..Self::default() }
+    }
+
+    pub fn with_param(mut self, key: impl Into<Cow<'static, str>>, value: impl Into<Cow<'static, str>>) -> Self {
+        self.params.insert(key.into(), value.into());
+        self
+    }
+
+    pub fn with_message(mut self, message: Option<impl Into<Cow<'static, str>>>) -> Self {
+        self.message = message.map(Into::into);
+        self
+    }
+
+    pub fn with_rule(mut self, rule: RuleDescriptor) -> Self {
+        self.rules.push(rule);
+        self
+    }
+}
+
+/// The rules of one field, keyed like its errors (`email`, `0`, `Card.number`;
+/// `__all__` for struct-level `schema(...)` hooks).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FieldRules {
+    pub field: Cow<'static, str>,
+    pub rules: Vec<RuleDescriptor>,
+}
+
+/// Types listing their validation rules, implemented by `#[derive(Validate)]`.
+pub trait ValidationRules {
+    fn validation_rules() -> Vec<FieldRules>;
+}
+
+/// Validators describing what they check.
+///
+/// Example
+/// ```
+/// use validator::prelude::*;
+///
+/// struct Slug;
+///
+/// impl Describe for Slug {
+///     fn describe(&self) -> RuleDescriptor {
+///         RuleDescriptor::new("Slug", "slug").with_param("pattern", "[a-z-]+")
+///     }
+/// }
+/// ```
+pub trait Describe {
+    fn describe(&self) -> RuleDescriptor;
+}
+
+impl<V: Describe + ?Sized> Describe for &V {
+    fn describe(&self) -> RuleDescriptor { (**self).describe() }
+}
+
+/// Helpers of the derived `ValidationRules` impls, not part of the public API.
+///
+/// Validators without a `Describe` impl are described by the name given in the
+/// annotation: the derive calls `describe_rule` on a reference to a probe,
+/// which picks the impl for the probe itself when `Describe` is implemented
+/// and the one for the reference to it otherwise.
+#[doc(hidden)]
+pub mod __private {
+    use super::{Describe, RuleDescriptor};
+
+    pub struct DescribeProbe<'a, V>(pub &'a V, pub &'static str);
+
+    pub trait ViaDescribe {
+        fn describe_rule(&self) -> RuleDescriptor;
+    }
+
+    impl<V: Describe> ViaDescribe for DescribeProbe<'_, V> {
+        fn describe_rule(&self) -> RuleDescriptor { self.0.describe() }
+    }
+
+    pub trait ViaName {
+        fn describe_rule(&self) -> RuleDescriptor;
+    }
+
+    impl<V> ViaName for &DescribeProbe<'_, V> {
+        fn describe_rule(&self) -> RuleDescriptor { RuleDescriptor::new(self.1, "") }
+    }
+}
@@ -1,68 +1,443 @@
 use crate::engine::error::ValidationError;
 use crate::engine::validator::Validator;
 use core::marker::PhantomData;
-use std::collections::HashMap;
+use crate::engine::errors::ValidationErrors;
+use crate::engine::state::ValidationState;
 #[cfg(feature = "async")]
 use std::future::Future;
 #[cfg(feature = "async")]
 use std::pin::Pin;
 #[cfg(feature = "async")]
-use crate::engine::validator::AsyncValidator;
+use crate::engine::validator::{AsyncValidator, StaticAsyncValidator};
 
 /// Trait for types that can validate themselves.
 ///
 /// Implementations should return `Ok(())` when valid or a `ValidationError`
 /// describing the first encountered problem.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not implement `Validate`",
+    label = "`#[validate(nested)]` requires `{Self}: Validate`",
+    note = "derive `Validate` for `{Self}` or implement it manually"
+)]
 pub trait Validate {
     /// Validate and stop at the first encountered error (default behavior).
     fn validate(&self) -> Result<(), ValidationError>;
 
-    /// Validate all rules and collect all errors per field.
-    /// Returns Ok(()) when there are no errors, or Err(HashMap) where keys
-    /// identify the field (or index for tuple structs) and values are vectors of
-    /// corresponding ValidationError for that field.
-    fn complete_validate(&self) -> Result<(), HashMap<String, Vec<ValidationError>>> {
+    /// Validate all rules and collect all errors.
+    /// Returns Ok(()) when there are no errors, or Err(ValidationErrors) holding
+    /// the errors per field (or index for tuple structs), nested values and
+    /// collection items.
+    fn complete_validate(&self) -> Result<(), ValidationErrors> {
         // Default implementation falls back to short-circuit validation.
         match self.validate() {
             Ok(()) => Ok(()),
             Err(e) => {
-                let mut map: HashMap<String, Vec<ValidationError>> = HashMap::new();
-                // Without field context, use a generic key and collect single error.
-                map.insert("value".to_string(), vec![e]);
-                Err(map)
+                // Without field context, report the error for the value itself.
+                let mut errors = ValidationErrors::new();
+                errors.add_error(e);
+                Err(errors)
             }
         }
     }
+
+    /// [`validate`](Self::validate) as part of the run tracked by `state`.
+    /// Derived impls use it to detect cycles between nested values and to
+    /// honor the [`ValidationOptions`](crate::prelude::ValidationOptions) of
+    /// the run; manual impls may ignore the state.
+    fn validate_in(&self, state: &mut ValidationState) -> Result<(), ValidationError> {
+        let _ = state;
+        self.validate()
+    }
+
+    /// [`complete_validate`](Self::complete_validate) as part of the run
+    /// tracked by `state`.
+    fn complete_validate_in(&self, state: &mut ValidationState) -> Result<(), ValidationErrors> {
+        self.complete_validate().inspect_err(|errors| state.record_errors(errors))
+    }
+}
+
+impl<T: Validate + ?Sized> Validate for &T {
+    fn validate(&self) -> Result<(), ValidationError> { (**self).validate() }
+    fn complete_validate(&self) -> Result<(), ValidationErrors> {
+        (**self).complete_validate()
+    }
+    fn validate_in(&self, state: &mut ValidationState) -> Result<(), ValidationError> {
+        (**self).validate_in(state)
+    }
+    fn complete_validate_in(&self, state: &mut ValidationState) -> Result<(), ValidationErrors> {
+        (**self).complete_validate_in(state)
+    }
+}
+
+impl<T: Validate + ?Sized> Validate for Box<T> {
+    fn validate(&self) -> Result<(), ValidationError> { (**self).validate() }
+    fn complete_validate(&self) -> Result<(), ValidationErrors> {
+        (**self).complete_validate()
+    }
+    fn validate_in(&self, state: &mut ValidationState) -> Result<(), ValidationError> {
+        (**self).validate_in(state)
+    }
+    fn complete_validate_in(&self, state: &mut ValidationState) -> Result<(), ValidationErrors> {
+        (**self).complete_validate_in(state)
+    }
+}
+
+/// Async variant of [`Validate`] for static dispatch, next to
+/// [`ValidateAsync`].
+///
+/// Derived together with `Validate` (with the `async` feature). The futures
+/// are not boxed per validator call and are `Send` whenever the fields, their
+/// validators and async functions allow it, so they can be awaited in
+/// `tokio::spawn` or web handlers. Nested values are boxed once each, which
+/// keeps recursive types possible. Implement it with `async fn`s.
+#[cfg(feature = "async")]
+pub trait StaticValidateAsync {
+    /// Validate and stop at the first encountered error (async).
+    fn validate_static(&self) -> impl Future<Output = Result<(), ValidationError>> + '_;
+
+    /// Validate all rules and collect all errors per field (async).
+    fn complete_validate_static(&self) -> impl Future<Output = Result<(), ValidationErrors>> + '_ {
+        // Default async implementation mirrors the sync fallback.
+        async move {
+            match self.validate_static().await {
+                Ok(()) => Ok(()),
+                Err(e) => {
+                    let mut errors = ValidationErrors::new();
+                    errors.add_error(e);
+                    Err(errors)
+                }
+            }
+        }
+    }
+
+    /// [`validate_static`](Self::validate_static) as part of the run tracked
+    /// by `state` (see [`Validate::validate_in`]).
+    fn validate_static_in<'a>(&'a self, state: &'a mut ValidationState) -> impl Future<Output = Result<(), ValidationError>> + 'a {
+        let _ = state;
+        self.validate_static()
+    }
+
+    /// [`complete_validate_static`](Self::complete_validate_static) as part of
+    /// the run tracked by `state`.
+    fn complete_validate_static_in<'a>(&'a self, state: &'a mut ValidationState) -> impl Future<Output = Result<(), ValidationErrors>> + 'a {
+        async move { self.complete_validate_static().await.inspect_err(|errors| state.record_errors(errors)) }
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T: StaticValidateAsync + ?Sized> StaticValidateAsync for &T {
+    fn validate_static(&self) -> impl Future<Output = Result<(), ValidationError>> + '_ {
+        (**self).validate_static()
+    }
+    fn complete_validate_static(&self) -> impl Future<Output = Result<(), ValidationErrors>> + '_ {
+        (**self).complete_validate_static()
+    }
+    fn validate_static_in<'a>(&'a self, state: &'a mut ValidationState) -> impl Future<Output = Result<(), ValidationError>> + 'a {
+        (**self).validate_static_in(state)
+    }
+    fn complete_validate_static_in<'a>(&'a self, state: &'a mut ValidationState) -> impl Future<Output = Result<(), ValidationErrors>> + 'a {
+        (**self).complete_validate_static_in(state)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T: StaticValidateAsync + ?Sized> StaticValidateAsync for Box<T> {
+    fn validate_static(&self) -> impl Future<Output = Result<(), ValidationError>> + '_ {
+        (**self).validate_static()
+    }
+    fn complete_validate_static(&self) -> impl Future<Output = Result<(), ValidationErrors>> + '_ {
+        (**self).complete_validate_static()
+    }
+    fn validate_static_in<'a>(&'a self, state: &'a mut ValidationState) -> impl Future<Output = Result<(), ValidationError>> + 'a {
+        (**self).validate_static_in(state)
+    }
+    fn complete_validate_static_in<'a>(&'a self, state: &'a mut ValidationState) -> impl Future<Output = Result<(), ValidationErrors>> + 'a {
+        (**self).complete_validate_static_in(state)
+    }
 }
 
 /// Async variant of [`Validate`].
+///
+/// Derived together with [`StaticValidateAsync`], boxing its futures. Types
+/// implementing it by hand can be `nested` in derived types; their futures are
+/// not `Send`.
 #[cfg(feature = "async")]
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not implement `ValidateAsync`",
+    label = "`#[validate(nested)]` requires `{Self}: ValidateAsync` or `{Self}: StaticValidateAsync`",
+    note = "derive `Validate` for `{Self}` or implement it manually"
+)]
 pub trait ValidateAsync {
     /// Validate and stop at the first encountered error (async).
     fn validate_async(&self) -> Pin<Box<dyn Future<Output = Result<(), ValidationError>> + '_>>;
 
     /// Validate all rules and collect all errors per field (async).
-    fn complete_validate_async(
-        &self,
-    ) -> Pin<
-        Box<
-            dyn Future<
-                Output = Result<(), HashMap<String, Vec<ValidationError>>>,
-            > + '_,
-        >,
-    > {
+    fn complete_validate_async(&self) -> Pin<Box<dyn Future<Output = Result<(), ValidationErrors>> + '_>> {
         // Default async implementation mirrors the sync fallback.
         Box::pin(async move {
             match self.validate_async().await {
                 Ok(()) => Ok(()),
                 Err(e) => {
-                    let mut map: HashMap<String, Vec<ValidationError>> = HashMap::new();
-                    map.insert("value".to_string(), vec![e]);
-                    Err(map)
+                    let mut errors = ValidationErrors::new();
+                    errors.add_error(e);
+                    Err(errors)
+                }
+            }
+        })
+    }
+
+    /// [`validate_async`](Self::validate_async) as part of the run tracked by
+    /// `state` (see [`Validate::validate_in`]).
+    fn validate_async_in<'a>(&'a self, state: &'a mut ValidationState) -> Pin<Box<dyn Future<Output = Result<(), ValidationError>> + 'a>> {
+        let _ = state;
+        self.validate_async()
+    }
+
+    /// [`complete_validate_async`](Self::complete_validate_async) as part of
+    /// the run tracked by `state`.
+    fn complete_validate_async_in<'a>(&'a self, state: &'a mut ValidationState) -> Pin<Box<dyn Future<Output = Result<(), ValidationErrors>> + 'a>> {
+        Box::pin(async move { self.complete_validate_async().await.inspect_err(|errors| state.record_errors(errors)) })
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T: ValidateAsync + ?Sized> ValidateAsync for &T {
+    fn validate_async(&self) -> Pin<Box<dyn Future<Output = Result<(), ValidationError>> + '_>> {
+        (**self).validate_async()
+    }
+    fn complete_validate_async(&self) -> Pin<Box<dyn Future<Output = Result<(), ValidationErrors>> + '_>> {
+        (**self).complete_validate_async()
+    }
+    fn validate_async_in<'a>(&'a self, state: &'a mut ValidationState) -> Pin<Box<dyn Future<Output = Result<(), ValidationError>> + 'a>> {
+        (**self).validate_async_in(state)
+    }
+    fn complete_validate_async_in<'a>(&'a self, state: &'a mut ValidationState) -> Pin<Box<dyn Future<Output = Result<(), ValidationErrors>> + 'a>> {
+        (**self).complete_validate_async_in(state)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T: ValidateAsync + ?Sized> ValidateAsync for Box<T> {
+    fn validate_async(&self) -> Pin<Box<dyn Future<Output = Result<(), ValidationError>> + '_>> {
+        (**self).validate_async()
+    }
+    fn complete_validate_async(&self) -> Pin<Box<dyn Future<Output = Result<(), ValidationErrors>> + '_>> {
+        (**self).complete_validate_async()
+    }
+    fn validate_async_in<'a>(&'a self, state: &'a mut ValidationState) -> Pin<Box<dyn Future<Output = Result<(), ValidationError>> + 'a>> {
+        (**self).validate_async_in(state)
+    }
+    fn complete_validate_async_in<'a>(&'a self, state: &'a mut ValidationState) -> Pin<Box<dyn Future<Output = Result<(), ValidationErrors>> + 'a>> {
+        (**self).complete_validate_async_in(state)
+    }
+}
+
+/// Validation with a context value of type `C`, for rules that need runtime
+/// data.
+///
+/// Derived for structs annotated with `#[validate(context = "C")]`: the context
+/// is passed on to validators (see
+/// [`ContextValidator`](crate::prelude::ContextValidator)), nested
+/// values and `use_context` functions. Structs deriving `Validate` implement
+/// `ValidateWith<C>` for any context by ignoring it.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be validated with context `{C}`",
+    label = "`{Self}` does not implement `ValidateWith<{C}>`",
+    note = "derive `Validate` for `{Self}` (with `#[validate(context = \"...\")]` if it needs the context)"
+)]
+pub trait ValidateWith<C: ?Sized> {
+    /// Validate with `ctx` and stop at the first encountered error.
+    fn validate_with(&self, ctx: &C) -> Result<(), ValidationError>;
+
+    /// Validate all rules with `ctx` and collect all errors.
+    fn complete_validate_with(&self, ctx: &C) -> Result<(), ValidationErrors> {
+        // Default implementation falls back to short-circuit validation.
+        match self.validate_with(ctx) {
+            Ok(()) => Ok(()),
+            Err(e) => {
+                let mut errors = ValidationErrors::new();
+                errors.add_error(e);
+                Err(errors)
+            }
+        }
+    }
+
+    /// [`validate_with`](Self::validate_with) as part of the run tracked by
+    /// `state` (see [`Validate::validate_in`]).
+    fn validate_with_in(&self, ctx: &C, state: &mut ValidationState) -> Result<(), ValidationError> {
+        let _ = state;
+        self.validate_with(ctx)
+    }
+
+    /// [`complete_validate_with`](Self::complete_validate_with) as part of the
+    /// run tracked by `state`.
+    fn complete_validate_with_in(&self, ctx: &C, state: &mut ValidationState) -> Result<(), ValidationErrors> {
+        self.complete_validate_with(ctx).inspect_err(|errors| state.record_errors(errors))
+    }
+}
+
+impl<T: ValidateWith<C> + ?Sized, C: ?Sized> ValidateWith<C> for &T {
+    fn validate_with(&self, ctx: &C) -> Result<(), ValidationError> { (**self).validate_with(ctx) }
+    fn complete_validate_with(&self, ctx: &C) -> Result<(), ValidationErrors> {
+        (**self).complete_validate_with(ctx)
+    }
+    fn validate_with_in(&self, ctx: &C, state: &mut ValidationState) -> Result<(), ValidationError> {
+        (**self).validate_with_in(ctx, state)
+    }
+    fn complete_validate_with_in(&self, ctx: &C, state: &mut ValidationState) -> Result<(), ValidationErrors> {
+        (**self).complete_validate_with_in(ctx, state)
+    }
+}
+
+impl<T: ValidateWith<C> + ?Sized, C: ?Sized> ValidateWith<C> for Box<T> {
+    fn validate_with(&self, ctx: &C) -> Result<(), ValidationError> { (**self).validate_with(ctx) }
+    fn complete_validate_with(&self, ctx: &C) -> Result<(), ValidationErrors> {
+        (**self).complete_validate_with(ctx)
+    }
+    fn validate_with_in(&self, ctx: &C, state: &mut ValidationState) -> Result<(), ValidationError> {
+        (**self).validate_with_in(ctx, state)
+    }
+    fn complete_validate_with_in(&self, ctx: &C, state: &mut ValidationState) -> Result<(), ValidationErrors> {
+        (**self).complete_validate_with_in(ctx, state)
+    }
+}
+
+/// Async variant of [`ValidateWith`] for static dispatch (see
+/// [`StaticValidateAsync`]).
+#[cfg(feature = "async")]
+pub trait StaticValidateWithAsync<C: ?Sized> {
+    /// Validate with `ctx` and stop at the first encountered error (async).
+    fn validate_with_static<'a>(&'a self, ctx: &'a C) -> impl Future<Output = Result<(), ValidationError>> + 'a;
+
+    /// Validate all rules with `ctx` and collect all errors (async).
+    fn complete_validate_with_static<'a>(&'a self, ctx: &'a C) -> impl Future<Output = Result<(), ValidationErrors>> + 'a {
+        async move {
+            match self.validate_with_static(ctx).await {
+                Ok(()) => Ok(()),
+                Err(e) => {
+                    let mut errors = ValidationErrors::new();
+                    errors.add_error(e);
+                    Err(errors)
+                }
+            }
+        }
+    }
+
+    /// [`validate_with_static`](Self::validate_with_static) as part of the run
+    /// tracked by `state` (see [`Validate::validate_in`]).
+    fn validate_with_static_in<'a>(&'a self, ctx: &'a C, state: &'a mut ValidationState) -> impl Future<Output = Result<(), ValidationError>> + 'a {
+        let _ = state;
+        self.validate_with_static(ctx)
+    }
+
+    /// [`complete_validate_with_static`](Self::complete_validate_with_static)
+    /// as part of the run tracked by `state`.
+    fn complete_validate_with_static_in<'a>(&'a self, ctx: &'a C, state: &'a mut ValidationState) -> impl Future<Output = Result<(), ValidationErrors>> + 'a {
+        async move { self.complete_validate_with_static(ctx).await.inspect_err(|errors| state.record_errors(errors)) }
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T: StaticValidateWithAsync<C> + ?Sized, C: ?Sized> StaticValidateWithAsync<C> for &T {
+    fn validate_with_static<'a>(&'a self, ctx: &'a C) -> impl Future<Output = Result<(), ValidationError>> + 'a {
+        (**self).validate_with_static(ctx)
+    }
+    fn complete_validate_with_static<'a>(&'a self, ctx: &'a C) -> impl Future<Output = Result<(), ValidationErrors>> + 'a {
+        (**self).complete_validate_with_static(ctx)
+    }
+    fn validate_with_static_in<'a>(&'a self, ctx: &'a C, state: &'a mut ValidationState) -> impl Future<Output = Result<(), ValidationError>> + 'a {
+        (**self).validate_with_static_in(ctx, state)
+    }
+    fn complete_validate_with_static_in<'a>(&'a self, ctx: &'a C, state: &'a mut ValidationState) -> impl Future<Output = Result<(), ValidationErrors>> + 'a {
+        (**self).complete_validate_with_static_in(ctx, state)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T: StaticValidateWithAsync<C> + ?Sized, C: ?Sized> StaticValidateWithAsync<C> for Box<T> {
+    fn validate_with_static<'a>(&'a self, ctx: &'a C) -> impl Future<Output = Result<(), ValidationError>> + 'a {
+        (**self).validate_with_static(ctx)
+    }
+    fn complete_validate_with_static<'a>(&'a self, ctx: &'a C) -> impl Future<Output = Result<(), ValidationErrors>> + 'a {
+        (**self).complete_validate_with_static(ctx)
+    }
+    fn validate_with_static_in<'a>(&'a self, ctx: &'a C, state: &'a mut ValidationState) -> impl Future<Output = Result<(), ValidationError>> + 'a {
+        (**self).validate_with_static_in(ctx, state)
+    }
+    fn complete_validate_with_static_in<'a>(&'a self, ctx: &'a C, state: &'a mut ValidationState) -> impl Future<Output = Result<(), ValidationErrors>> + 'a {
+        (**self).complete_validate_with_static_in(ctx, state)
+    }
+}
+
+/// Async variant of [`ValidateWith`], derived together with
+/// [`StaticValidateWithAsync`] (see [`ValidateAsync`]).
+#[cfg(feature = "async")]
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be validated asynchronously with context `{C}`",
+    label = "`#[validate(nested)]` requires `{Self}: ValidateWithAsync<{C}>` or `{Self}: StaticValidateWithAsync<{C}>`"
+)]
+pub trait ValidateWithAsync<C: ?Sized> {
+    /// Validate with `ctx` and stop at the first encountered error (async).
+    fn validate_with_async<'a>(&'a self, ctx: &'a C) -> Pin<Box<dyn Future<Output = Result<(), ValidationError>> + 'a>>;
+
+    /// Validate all rules with `ctx` and collect all errors (async).
+    fn complete_validate_with_async<'a>(&'a self, ctx: &'a C) -> Pin<Box<dyn Future<Output = Result<(), ValidationErrors>> + 'a>> {
+        Box::pin(async move {
+            match self.validate_with_async(ctx).await {
+                Ok(()) => Ok(()),
+                Err(e) => {
+                    let mut errors = ValidationErrors::new();
+                    errors.add_error(e);
+                    Err(errors)
                 }
             }
         })
     }
+
+    /// [`validate_with_async`](Self::validate_with_async) as part of the run
+    /// tracked by `state` (see [`Validate::validate_in`]).
+    fn validate_with_async_in<'a>(&'a self, ctx: &'a C, state: &'a mut ValidationState) -> Pin<Box<dyn Future<Output = Result<(), ValidationError>> + 'a>> {
+        let _ = state;
+        self.validate_with_async(ctx)
+    }
+
+    /// [`complete_validate_with_async`](Self::complete_validate_with_async) as
+    /// part of the run tracked by `state`.
+    fn complete_validate_with_async_in<'a>(&'a self, ctx: &'a C, state: &'a mut ValidationState) -> Pin<Box<dyn Future<Output = Result<(), ValidationErrors>> + 'a>> {
+        Box::pin(async move { self.complete_validate_with_async(ctx).await.inspect_err(|errors| state.record_errors(errors)) })
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T: ValidateWithAsync<C> + ?Sized, C: ?Sized> ValidateWithAsync<C> for &T {
+    fn validate_with_async<'a>(&'a self, ctx: &'a C) -> Pin<Box<dyn Future<Output = Result<(), ValidationError>> + 'a>> {
+        (**self).validate_with_async(ctx)
+    }
+    fn complete_validate_with_async<'a>(&'a self, ctx: &'a C) -> Pin<Box<dyn Future<Output = Result<(), ValidationErrors>> + 'a>> {
+        (**self).complete_validate_with_async(ctx)
+    }
+    fn validate_with_async_in<'a>(&'a self, ctx: &'a C, state: &'a mut ValidationState) -> Pin<Box<dyn Future<Output = Result<(), ValidationError>> + 'a>> {
+        (**self).validate_with_async_in(ctx, state)
+    }
+    fn complete_validate_with_async_in<'a>(&'a self, ctx: &'a C, state: &'a mut ValidationState) -> Pin<Box<dyn Future<Output = Result<(), ValidationErrors>> + 'a>> {
+        (**self).complete_validate_with_async_in(ctx, state)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T: ValidateWithAsync<C> + ?Sized, C: ?Sized> ValidateWithAsync<C> for Box<T> {
+    fn validate_with_async<'a>(&'a self, ctx: &'a C) -> Pin<Box<dyn Future<Output = Result<(), ValidationError>> + 'a>> {
+        (**self).validate_with_async(ctx)
+    }
+    fn complete_validate_with_async<'a>(&'a self, ctx: &'a C) -> Pin<Box<dyn Future<Output = Result<(), ValidationErrors>> + 'a>> {
+        (**self).complete_validate_with_async(ctx)
+    }
+    fn validate_with_async_in<'a>(&'a self, ctx: &'a C, state: &'a mut ValidationState) -> Pin<Box<dyn Future<Output = Result<(), ValidationError>> + 'a>> {
+        (**self).validate_with_async_in(ctx, state)
+    }
+    fn complete_validate_with_async_in<'a>(&'a self, ctx: &'a C, state: &'a mut ValidationState) -> Pin<Box<dyn Future<Output = Result<(), ValidationErrors>> + 'a>> {
+        (**self).complete_validate_with_async_in(ctx, state)
+    }
 }
 
 /// Adapter that pairs a value `T` with a validator `V` (which implements
@@ -99,4 +474,15 @@ where
             v.validate_async(&self.0).await
         })
     }
-}
\ No newline at end of file
+}
+
+#[cfg(feature = "async")]
+impl<V, T> StaticValidateAsync for With<V, T>
+where
+    V: StaticAsyncValidator<T> + Default,
+{
+    async fn validate_static(&self) -> Result<(), ValidationError> {
+        let v = V::default();
+        v.validate_static(&self.0).await
+    }
+}
@@ -0,0 +1,301 @@
+use crate::prelude::*;
+#[cfg(feature = "json_schema")]
+use crate::json_schema::{JsonSchemaRule, fragment};
+#[cfg(feature = "json_schema")]
+use serde_json::Value;
+use std::borrow::Cow;
+
+/// Composition helpers for validators, implemented by the built-in validators
+/// and the combinators themselves. Implement it with an empty `impl` block to
+/// combine your own validators.
+///
+/// Example
+/// ```
+/// use validator::prelude::Validator;
+/// use validator::validators::{Email, MaxLength, MinLength, ValidatorExt};
+///
+/// let login = Email::default().or(MinLength::new(3).and(MaxLength::new(10)));
+/// assert!(login.validate("jo@example.com").is_ok());
+/// assert!(login.validate("john").is_ok());
+/// let err = login.validate("jo").unwrap_err();
+/// assert_eq!(err.code, "any_of");
+/// assert_eq!(err.params["codes"], "email, min_length");
+///
+/// let trimmed = MinLength::new(3).map(|s: &String| s.trim().to_string());
+/// assert!(trimmed.validate(&" ab ".to_string()).is_err());
+/// ```
+pub trait ValidatorExt: Sized {
+    /// Valid when both `self` and `other` accept the value; the first failure
+    /// is returned as it is.
+    fn and<V>(self, other: V) -> And<Self, V> { AllOf::new((self, other)) }
+
+    /// Valid when `self` or `other` accepts the value (see [`AnyOf`]).
+    fn or<V>(self, other: V) -> Or<Self, V> { AnyOf::new((self, other)) }
+
+    /// Valid when `self` rejects the value.
+    fn not(self) -> Not<Self> { Not::new(self) }
+
+    /// Validates `Option`s, accepting `None`.
+    fn optional(self) -> Optional<Self> { Optional(self) }
+
+    /// Validates the value `f` projects from the validated value.
+    fn map<F>(self, f: F) -> Map<Self, F> { Map { validator: self, f } }
+}
+
+/// Validators run one after another by [`AllOf`] and [`AnyOf`]: tuples of up
+/// to eight validators and `Vec<Box<dyn Validator<T>>>`.
+pub trait ValidatorList<T: ?Sized> {
+    /// Run the validators in order, handing every result to `f` until it
+    /// returns `false`.
+    fn run(&self, value: &T, f: &mut dyn FnMut(Result<(), ValidationError>) -> bool);
+}
+
+impl<T: ?Sized> ValidatorList<T> for Vec<Box<dyn Validator<T>>> {
+    fn run(&self, value: &T, f: &mut dyn FnMut(Result<(), ValidationError>) -> bool) {
+        for validator in self {
+            if !f(validator.validate(value)) {
+                return;
+            }
+        }
+    }
+}
+
+/// Validator lists whose validators all describe themselves, see [`Describe`].
+pub trait DescribeList {
+    /// The descriptors of the validators of the list, in order.
+    fn describe_all(&self) -> Vec<RuleDescriptor>;
+}
+
+/// Validator lists whose validators all describe themselves as JSON Schema,
+/// see [`JsonSchemaRule`].
+#[cfg(feature = "json_schema")]
+pub trait JsonSchemaList {
+    /// Call `f` with every validator of the list, in order.
+    fn each_rule(&self, f: &mut dyn FnMut(&dyn JsonSchemaRule));
+}
+
+macro_rules! tuple_validator_list {
+    ($($name:ident $index:tt),+) => {
+        impl<T: ?Sized, $($name: Validator<T>),+> ValidatorList<T> for ($($name,)+) {
+            fn run(&self, value: &T, f: &mut dyn FnMut(Result<(), ValidationError>) -> bool) {
+                $(
+                    if !f(self.$index.validate(value)) {
+                        return;
+                    }
+                )+
+            }
+        }
+
+        impl<$($name: Describe),+> DescribeList for ($($name,)+) {
+            fn describe_all(&self) -> Vec<RuleDescriptor> {
+                vec![$(self.$index.describe()),+]
+            }
+        }
+
+        #[cfg(feature = "json_schema")]
+        impl<$($name: JsonSchemaRule),+> JsonSchemaList for ($($name,)+) {
+            fn each_rule(&self, f: &mut dyn FnMut(&dyn JsonSchemaRule)) {
+                $(f(&self.$index);)+
+            }
+        }
+    };
+}
+
+tuple_validator_list!(A 0);
+tuple_validator_list!(A 0, B 1);
+tuple_validator_list!(A 0, B 1, C 2);
+tuple_validator_list!(A 0, B 1, C 2, D 3);
+tuple_validator_list!(A 0, B 1, C 2, D 3, E 4);
+tuple_validator_list!(A 0, B 1, C 2, D 3, E 4, F 5);
+tuple_validator_list!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+tuple_validator_list!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+
+/// Valid when every validator of the list accepts the value. Stops at the first
+/// failure and returns it unchanged.
+pub struct AllOf<L> {
+    pub validators: L,
+}
+
+impl<L> AllOf<L> {
+    pub fn new(validators: L) -> Self { Self { validators } }
+}
+
+impl<T: ?Sized, L: ValidatorList<T>> Validator<T> for AllOf<L> {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        let mut result = Ok(());
+        self.validators.run(value, &mut |r| {
+            result = r;
+            result.is_ok()
+        });
+        result
+    }
+}
+
+/// Valid when any validator of the list accepts the value.
+///
+/// When all of them fail, the error has the `any_of` code; the `codes` param
+/// lists the codes of the failures and the `errors` param their messages.
+pub struct AnyOf<L> {
+    pub validators: L,
+    pub message: Option<Cow<'static, str>>,
+}
+
+impl<L> AnyOf<L> {
+    pub fn new(validators: L) -> Self { Self { validators, message: None } }
+    pub fn with_message(mut self, msg: impl Into<Cow<'static, str>>) -> Self {
+        self.message = Some(msg.into());
+        self
+    }
+}
+
+impl<T: ?Sized, L: ValidatorList<T>> Validator<T> for AnyOf<L> {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        let mut failures = Vec::new();
+        let mut valid = false;
+        self.validators.run(value, &mut |r| match r {
+            Ok(()) => {
+                valid = true;
+                false
+            }
+            Err(e) => {
+                failures.push(e);
+                true
+            }
+        });
+        if valid {
+            return Ok(());
+        }
+        let default_msg = "Must satisfy one of: {errors}";
+        let msg = self.message.as_deref().unwrap_or(default_msg);
+        let codes: Vec<_> = failures.iter().map(|e| e.code.as_ref()).collect();
+        let errors: Vec<_> = failures.iter().map(|e| e.message.as_ref()).collect();
+        Err(
+            ValidationError::new("any_of", msg.to_string())
+                .with_param("codes", codes.join(", "))
+                .with_param("errors", errors.join("; "))
+                .render(),
+        )
+    }
+}
+
+/// Valid when both `A` and `B` accept the value, see [`ValidatorExt::and`].
+pub type And<A, B> = AllOf<(A, B)>;
+
+/// Valid when `A` or `B` accepts the value, see [`ValidatorExt::or`].
+pub type Or<A, B> = AnyOf<(A, B)>;
+
+/// Valid when the inner validator rejects the value; fails with the `not` code.
+pub struct Not<V> {
+    pub validator: V,
+    pub message: Option<Cow<'static, str>>,
+}
+
+impl<V> Not<V> {
+    pub fn new(validator: V) -> Self { Self { validator, message: None } }
+    pub fn with_message(mut self, msg: impl Into<Cow<'static, str>>) -> Self {
+        self.message = Some(msg.into());
+        self
+    }
+}
+
+impl<T: ?Sized, V: Validator<T>> Validator<T> for Not<V> {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        match self.validator.validate(value) {
+            Ok(()) => {
+                let default_msg = "Value is not allowed";
+                let msg = self.message.as_deref().unwrap_or(default_msg);
+                Err(ValidationError::new("not", msg.to_string()))
+            }
+            Err(_) => Ok(()),
+        }
+    }
+}
+
+/// Validates the content of an `Option` with the inner validator; `None` is
+/// valid.
+pub struct Optional<V>(pub V);
+
+impl<T, V: Validator<T>> Validator<Option<T>> for Optional<V> {
+    fn validate(&self, value: &Option<T>) -> Result<(), ValidationError> {
+        match value {
+            Some(value) => self.0.validate(value),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Validates the value `f` projects from the validated value, e.g. its trimmed
+/// form or one of its parts. Errors of the inner validator are returned as
+/// they are.
+pub struct Map<V, F> {
+    pub validator: V,
+    pub f: F,
+}
+
+impl<T: ?Sized, U, V: Validator<U>, F: Fn(&T) -> U> Validator<T> for Map<V, F> {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        self.validator.validate(&(self.f)(value))
+    }
+}
+
+impl<L: DescribeList> Describe for AllOf<L> {
+    fn describe(&self) -> RuleDescriptor {
+        RuleDescriptor { rules: self.validators.describe_all(), ..RuleDescriptor::new("AllOf", "") }
+    }
+}
+
+impl<L: DescribeList> Describe for AnyOf<L> {
+    fn describe(&self) -> RuleDescriptor {
+        let descriptor = RuleDescriptor::new("AnyOf", "any_of").with_message(self.message.clone());
+        RuleDescriptor { rules: self.validators.describe_all(), ..descriptor }
+    }
+}
+
+impl<V: Describe> Describe for Not<V> {
+    fn describe(&self) -> RuleDescriptor {
+        RuleDescriptor::new("Not", "not").with_message(self.message.clone()).with_rule(self.validator.describe())
+    }
+}
+
+impl<V: Describe> Describe for Optional<V> {
+    fn describe(&self) -> RuleDescriptor { RuleDescriptor::new("Optional", "").with_rule(self.0.describe()) }
+}
+
+impl<V: Describe, F> Describe for Map<V, F> {
+    fn describe(&self) -> RuleDescriptor { RuleDescriptor::new("Map", "").with_rule(self.validator.describe()) }
+}
+
+impl<L> ValidatorExt for AllOf<L> {}
+impl<L> ValidatorExt for AnyOf<L> {}
+impl<V> ValidatorExt for Not<V> {}
+impl<V> ValidatorExt for Optional<V> {}
+impl<V, F> ValidatorExt for Map<V, F> {}
+
+#[cfg(feature = "json_schema")]
+impl<L: JsonSchemaList> JsonSchemaRule for AllOf<L> {
+    fn extend_schema(&self, schema: &mut serde_json::Map<String, Value>) {
+        self.validators.each_rule(&mut |rule| rule.extend_schema(schema));
+    }
+}
+
+#[cfg(feature = "json_schema")]
+impl<L: JsonSchemaList> JsonSchemaRule for AnyOf<L> {
+    fn extend_schema(&self, schema: &mut serde_json::Map<String, Value>) {
+        let mut alternatives = Vec::new();
+        self.validators.each_rule(&mut |rule| alternatives.push(fragment(rule, schema)));
+        schema.insert("anyOf".into(), Value::Array(alternatives));
+    }
+}
+
+#[cfg(feature = "json_schema")]
+impl<V: JsonSchemaRule> JsonSchemaRule for Not<V> {
+    fn extend_schema(&self, schema: &mut serde_json::Map<String, Value>) {
+        let not = fragment(&self.validator, schema);
+        schema.insert("not".into(), not);
+    }
+}
+
+#[cfg(feature = "json_schema")]
+impl<V: JsonSchemaRule> JsonSchemaRule for Optional<V> {
+    fn extend_schema(&self, schema: &mut serde_json::Map<String, Value>) { self.0.extend_schema(schema) }
+}
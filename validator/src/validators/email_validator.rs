@@ -1,37 +1,265 @@
 use crate::prelude::*;
-use once_cell::sync::Lazy;
-use regex::Regex;
 use std::borrow::Cow;
+use std::net::{Ipv4Addr, Ipv6Addr};
 
-pub trait EmailValidator: Validator<String> {}
+pub trait EmailValidator<T: ?Sized>: Validator<T> {}
 
+/// Maximum length of the local part (RFC 5321, section 4.5.3.1.1).
+const MAX_LOCAL_LEN: usize = 64;
+/// Maximum length of a domain label (RFC 1035, section 2.3.4).
+const MAX_LABEL_LEN: usize = 63;
+/// Maximum length of a domain name (RFC 5321, section 4.5.3.1.2).
+const MAX_DOMAIN_LEN: usize = 253;
+/// Maximum length of a forward-path address (RFC 5321, section 4.5.3.1.3).
+const MAX_EMAIL_LEN: usize = 254;
+
+/// How strictly `Email` parses addresses.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EmailMode {
+    /// Accept anything shaped like `local@domain` without whitespace, with
+    /// non-empty domain labels and within the RFC length limits.
+    #[default]
+    Permissive,
+    /// The `type=email` grammar of the HTML standard: an unquoted local part of
+    /// `atext` and dots, and letter-digit-hyphen domain labels.
+    Html5,
+    /// RFC 5321/5322 addresses: dot-atom or quoted-string local part, and
+    /// letter-digit-hyphen domain labels.
+    Rfc5322,
+}
+
+/// Validates email addresses.
+///
+/// All modes enforce the RFC length limits (local part 64, label 63, domain 253,
+/// address 254 octets). `Permissive` and `Html5` modes accept dotless intranet
+/// domains (`user@localhost`), like `<input type="email">`; `Rfc5322` mode
+/// requires a dot. `require_tld` overrides the mode. Internationalized
+/// domains are checked in their punycode form, IP-literal domains
+/// (`user@[192.168.0.1]`, `user@[IPv6:::1]`) are only accepted with
+/// `allow_ip_literal(true)`. `allowed_domains`/`denied_domains` match the
+/// domain itself and its subdomains, case-insensitively and in punycode form
+/// (`bücher.de` matches `xn--bcher-kva.de`).
+///
+/// Errors use the `email` code; the `reason` param tells `format`, `length`,
+/// `domain_not_allowed` or `domain_denied` apart.
 pub struct Email {
+    pub mode: EmailMode,
+    /// `None` follows the mode: required in `Rfc5322` mode only.
+    pub require_tld: Option<bool>,
+    pub allow_idn: bool,
+    pub allow_ip_literal: bool,
+    pub allowed_domains: Vec<String>,
+    pub denied_domains: Vec<String>,
     pub message: Option<Cow<'static, str>>,
 }
 
-impl Default for Email { fn default() -> Self { Email { message: None } } }
+impl Default for Email {
+    fn default() -> Self {
+        Email {
+            mode: EmailMode::default(),
+            require_tld: None,
+            allow_idn: true,
+            allow_ip_literal: false,
+            allowed_domains: Vec::new(),
+            denied_domains: Vec::new(),
+            message: None,
+        }
+    }
+}
 
 impl Email {
+    pub fn new(mode: EmailMode) -> Self { Self::default().mode(mode) }
+    pub fn mode(mut self, mode: EmailMode) -> Self {
+        self.mode = mode;
+        self
+    }
+    pub fn require_tld(mut self, require_tld: bool) -> Self {
+        self.require_tld = Some(require_tld);
+        self
+    }
+    pub fn allow_idn(mut self, allow_idn: bool) -> Self {
+        self.allow_idn = allow_idn;
+        self
+    }
+    pub fn allow_ip_literal(mut self, allow_ip_literal: bool) -> Self {
+        self.allow_ip_literal = allow_ip_literal;
+        self
+    }
+    pub fn allowed_domains<I, S>(mut self, domains: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.allowed_domains = domains.into_iter().map(|d| ascii_domain(d.into())).collect();
+        self
+    }
+    pub fn denied_domains<I, S>(mut self, domains: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.denied_domains = domains.into_iter().map(|d| ascii_domain(d.into())).collect();
+        self
+    }
     pub fn with_message(mut self, msg: impl Into<Cow<'static, str>>) -> Self {
         self.message = Some(msg.into());
         self
     }
-}
 
-static EMAIL_RE: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r"^[^@\s]+@[^@\s]+\.[^@\s]+$").expect("valid email regex")
-});
+    fn error(&self, reason: &'static str, default_msg: &'static str) -> ValidationError {
+        let msg = self.message.as_deref().unwrap_or(default_msg);
+        ValidationError::new("email", msg.to_string()).with_param("reason", reason).render()
+    }
 
-impl Validator<String> for Email {
-    fn validate(&self, value: &String) -> Result<(), ValidationError> {
-        if EMAIL_RE.is_match(value) {
-            Ok(())
+    /// Check the address and return its ASCII (punycode) domain, or the
+    /// reason why it is invalid.
+    fn check(&self, value: &str) -> Result<String, &'static str> {
+        let (local, domain) = value.rsplit_once('@').ok_or("format")?;
+        if !self.check_local(local) {
+            return Err("format");
+        }
+        if local.len() > MAX_LOCAL_LEN {
+            return Err("length");
+        }
+        if let Some(literal) = domain.strip_prefix('[') {
+            if !self.allow_ip_literal || !is_ip_literal(literal.strip_suffix(']').ok_or("format")?) {
+                return Err("format");
+            }
+            return Ok(domain.to_string());
+        }
+        let ascii_domain = if domain.is_ascii() {
+            domain.to_ascii_lowercase()
+        } else if self.allow_idn {
+            idna::domain_to_ascii(domain).map_err(|_| "format")?
         } else {
-            let default_msg = "Invalid email format";
-            let msg = self.message.as_deref().unwrap_or(default_msg);
-            Err(ValidationError::new("email", msg.to_string()))
+            return Err("format");
+        };
+        if !self.check_domain(&ascii_domain) {
+            return Err("format");
+        }
+        if ascii_domain.len() > MAX_DOMAIN_LEN || local.len() + 1 + ascii_domain.len() > MAX_EMAIL_LEN {
+            return Err("length");
         }
+        Ok(ascii_domain)
+    }
+
+    fn check_local(&self, local: &str) -> bool {
+        match self.mode {
+            EmailMode::Permissive => !local.is_empty() && !local.contains(|c: char| c == '@' || c.is_whitespace()),
+            EmailMode::Html5 => !local.is_empty() && local.chars().all(|c| c == '.' || is_atext(c)),
+            EmailMode::Rfc5322 => is_dot_atom(local) || is_quoted_string(local),
+        }
+    }
+
+    fn check_domain(&self, domain: &str) -> bool {
+        let labels: Vec<&str> = domain.split('.').collect();
+        if self.require_tld.unwrap_or(self.mode == EmailMode::Rfc5322) && labels.len() < 2 {
+            return false;
+        }
+        labels.iter().all(|label| {
+            !label.is_empty()
+                && label.len() <= MAX_LABEL_LEN
+                && match self.mode {
+                    EmailMode::Permissive => !label.contains(|c: char| c == '@' || c.is_whitespace()),
+                    EmailMode::Html5 | EmailMode::Rfc5322 => is_ldh_label(label),
+                }
+        })
+    }
+
+    fn domain_matches(list: &[String], domain: &str) -> bool {
+        list.iter().any(|d| domain == d || domain.strip_suffix(d.as_str()).is_some_and(|sub| sub.ends_with('.')))
+    }
+}
+
+/// `domain` in the form `check` returns, so that list entries written as
+/// internationalized domains (`bücher.de`) match their punycode form.
+fn ascii_domain(domain: String) -> String {
+    idna::domain_to_ascii(&domain).unwrap_or_else(|_| domain.to_ascii_lowercase())
+}
+
+/// `atext` of RFC 5322, section 3.2.3.
+fn is_atext(c: char) -> bool {
+    c.is_ascii_alphanumeric() || "!#$%&'*+-/=?^_`{|}~".contains(c)
+}
+
+/// `dot-atom-text` of RFC 5322: atoms separated by single dots.
+fn is_dot_atom(local: &str) -> bool {
+    local.split('.').all(|atom| !atom.is_empty() && atom.chars().all(is_atext))
+}
+
+/// `quoted-string` of RFC 5322 (without folding white space).
+fn is_quoted_string(local: &str) -> bool {
+    let Some(inner) = local.strip_prefix('"').and_then(|l| l.strip_suffix('"')) else { return false };
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some(escaped) if escaped == ' ' || escaped == '\t' || escaped.is_ascii_graphic() => {}
+                _ => return false,
+            },
+            '"' => return false,
+            c if c == ' ' || c.is_ascii_graphic() => {}
+            _ => return false,
+        }
+    }
+    true
+}
+
+/// Letter-digit-hyphen label of RFC 1035 (hyphens not at the ends).
+fn is_ldh_label(label: &str) -> bool {
+    label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') && !label.starts_with('-') && !label.ends_with('-')
+}
+
+/// `address-literal` of RFC 5321, section 4.1.3 (without the brackets).
+fn is_ip_literal(literal: &str) -> bool {
+    match literal.strip_prefix("IPv6:") {
+        Some(v6) => v6.parse::<Ipv6Addr>().is_ok(),
+        None => literal.parse::<Ipv4Addr>().is_ok(),
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Validator<T> for Email {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        let domain = match self.check(value.as_ref()) {
+            Ok(domain) => domain,
+            Err("length") => return Err(self.error("length", "Email address is too long")),
+            Err(reason) => return Err(self.error(reason, "Invalid email format")),
+        };
+        if !self.allowed_domains.is_empty() && !Self::domain_matches(&self.allowed_domains, &domain) {
+            return Err(self.error("domain_not_allowed", "Email domain is not allowed"));
+        }
+        if Self::domain_matches(&self.denied_domains, &domain) {
+            return Err(self.error("domain_denied", "Email domain is not allowed"));
+        }
+        Ok(())
     }
 }
 
-impl EmailValidator for Email {}
\ No newline at end of file
+impl<T: AsRef<str> + ?Sized> EmailValidator<T> for Email {}
+
+impl crate::validators::ValidatorExt for Email {}
+
+#[cfg(feature = "json_schema")]
+impl crate::json_schema::JsonSchemaRule for Email {
+    fn extend_schema(&self, schema: &mut serde_json::Map<String, serde_json::Value>) {
+        schema.insert("format".into(), "email".into());
+    }
+}
+
+impl Describe for Email {
+    fn describe(&self) -> RuleDescriptor {
+        let mode = match self.mode {
+            EmailMode::Permissive => "permissive",
+            EmailMode::Html5 => "html5",
+            EmailMode::Rfc5322 => "rfc5322",
+        };
+        let mut descriptor = RuleDescriptor::new("Email", "email").with_param("mode", mode);
+        if !self.allowed_domains.is_empty() {
+            descriptor = descriptor.with_param("allowed_domains", self.allowed_domains.join(", "));
+        }
+        if !self.denied_domains.is_empty() {
+            descriptor = descriptor.with_param("denied_domains", self.denied_domains.join(", "));
+        }
+        descriptor.with_message(self.message.clone())
+    }
+}
@@ -0,0 +1,108 @@
+use crate::prelude::*;
+use std::borrow::Cow;
+
+/// Checks that a field is greater than another field of the same struct:
+/// ```
+/// use validator::prelude::*;
+/// use validator::validators::GreaterThanField;
+///
+/// #[derive(validator::Validate)]
+/// struct Span {
+///     start: u32,
+///     #[validate(GreaterThanField(other = "start"))]
+///     end: u32,
+/// }
+///
+/// assert!(Span { start: 1, end: 2 }.validate().is_ok());
+/// assert_eq!(Span { start: 2, end: 2 }.validate().unwrap_err().code, "greater_than_field");
+/// ```
+/// `or_equal = true` also accepts equal values.
+#[derive(Default)]
+pub struct GreaterThanField {
+    pub or_equal: bool,
+    pub message: Option<Cow<'static, str>>,
+}
+
+impl GreaterThanField {
+    pub fn or_equal(mut self, or_equal: bool) -> Self {
+        self.or_equal = or_equal;
+        self
+    }
+    pub fn with_message(mut self, msg: impl Into<Cow<'static, str>>) -> Self {
+        self.message = Some(msg.into());
+        self
+    }
+}
+
+impl<T: PartialOrd<U> + ?Sized, U: ?Sized> CrossFieldValidator<T, U> for GreaterThanField {
+    fn validate(&self, value: &T, other: &U) -> Result<(), ValidationError> {
+        if value > other || (self.or_equal && value == other) {
+            Ok(())
+        } else {
+            let default_msg = "Value must be greater than {other}";
+            let msg = self.message.as_deref().unwrap_or(default_msg);
+            Err(ValidationError::new("greater_than_field", msg.to_string()))
+        }
+    }
+}
+
+/// Checks that a field is less than another field of the same struct:
+/// ```
+/// use validator::prelude::*;
+/// use validator::validators::LessThanField;
+///
+/// #[derive(validator::Validate)]
+/// struct PriceFilter {
+///     #[validate(LessThanField(other = "max_price", or_equal = true))]
+///     min_price: u32,
+///     max_price: u32,
+/// }
+///
+/// assert!(PriceFilter { min_price: 10, max_price: 10 }.validate().is_ok());
+/// assert_eq!(PriceFilter { min_price: 20, max_price: 10 }.validate().unwrap_err().code, "less_than_field");
+/// ```
+/// `or_equal = true` also accepts equal values.
+#[derive(Default)]
+pub struct LessThanField {
+    pub or_equal: bool,
+    pub message: Option<Cow<'static, str>>,
+}
+
+impl LessThanField {
+    pub fn or_equal(mut self, or_equal: bool) -> Self {
+        self.or_equal = or_equal;
+        self
+    }
+    pub fn with_message(mut self, msg: impl Into<Cow<'static, str>>) -> Self {
+        self.message = Some(msg.into());
+        self
+    }
+}
+
+impl<T: PartialOrd<U> + ?Sized, U: ?Sized> CrossFieldValidator<T, U> for LessThanField {
+    fn validate(&self, value: &T, other: &U) -> Result<(), ValidationError> {
+        if value < other || (self.or_equal && value == other) {
+            Ok(())
+        } else {
+            let default_msg = "Value must be less than {other}";
+            let msg = self.message.as_deref().unwrap_or(default_msg);
+            Err(ValidationError::new("less_than_field", msg.to_string()))
+        }
+    }
+}
+
+impl Describe for GreaterThanField {
+    fn describe(&self) -> RuleDescriptor {
+        RuleDescriptor::new("GreaterThanField", "greater_than_field")
+            .with_param("or_equal", self.or_equal.to_string())
+            .with_message(self.message.clone())
+    }
+}
+
+impl Describe for LessThanField {
+    fn describe(&self) -> RuleDescriptor {
+        RuleDescriptor::new("LessThanField", "less_than_field")
+            .with_param("or_equal", self.or_equal.to_string())
+            .with_message(self.message.clone())
+    }
+}
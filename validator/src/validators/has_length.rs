@@ -0,0 +1,60 @@
+use crate::validators::length_mode::LengthMode;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::rc::Rc;
+use std::sync::Arc;
+
+/// Values with a length, as checked by `MinLength`, `MaxLength` and `Length`.
+///
+/// Strings are counted according to the requested [`LengthMode`]; collections
+/// always count their items.
+pub trait HasLength {
+    fn length(&self, mode: LengthMode) -> usize;
+}
+
+impl<T: HasLength + ?Sized> HasLength for &T {
+    fn length(&self, mode: LengthMode) -> usize { (**self).length(mode) }
+}
+
+impl<T: HasLength + ?Sized> HasLength for Box<T> {
+    fn length(&self, mode: LengthMode) -> usize { (**self).length(mode) }
+}
+
+impl<T: HasLength + ?Sized> HasLength for Rc<T> {
+    fn length(&self, mode: LengthMode) -> usize { (**self).length(mode) }
+}
+
+impl<T: HasLength + ?Sized> HasLength for Arc<T> {
+    fn length(&self, mode: LengthMode) -> usize { (**self).length(mode) }
+}
+
+impl HasLength for str {
+    fn length(&self, mode: LengthMode) -> usize { mode.count(self) }
+}
+
+impl HasLength for String {
+    fn length(&self, mode: LengthMode) -> usize { mode.count(self) }
+}
+
+impl HasLength for Cow<'_, str> {
+    fn length(&self, mode: LengthMode) -> usize { mode.count(self) }
+}
+
+macro_rules! impl_has_length_for_collection {
+    ($([$($generics:tt)*] $ty:ty),* $(,)?) => {
+        $(impl<$($generics)*> HasLength for $ty {
+            fn length(&self, _mode: LengthMode) -> usize { self.len() }
+        })*
+    };
+}
+
+impl_has_length_for_collection! {
+    [T] [T],
+    [T, const N: usize] [T; N],
+    [T] Vec<T>,
+    [T] VecDeque<T>,
+    [T, S] HashSet<T, S>,
+    [T] BTreeSet<T>,
+    [K, V, S] HashMap<K, V, S>,
+    [K, V] BTreeMap<K, V>,
+}
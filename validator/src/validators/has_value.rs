@@ -0,0 +1,56 @@
+use crate::validators::has_length::HasLength;
+use crate::validators::length_mode::LengthMode;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::rc::Rc;
+use std::sync::Arc;
+
+/// Values that can be "missing", as checked by `RequiredIf`, `RequiredUnless`
+/// and `MutuallyExclusive`.
+///
+/// `None`, empty strings and empty collections have no value.
+pub trait HasValue {
+    fn has_value(&self) -> bool;
+}
+
+impl<T: HasValue + ?Sized> HasValue for &T {
+    fn has_value(&self) -> bool { (**self).has_value() }
+}
+
+impl<T: HasValue + ?Sized> HasValue for Box<T> {
+    fn has_value(&self) -> bool { (**self).has_value() }
+}
+
+impl<T: HasValue + ?Sized> HasValue for Rc<T> {
+    fn has_value(&self) -> bool { (**self).has_value() }
+}
+
+impl<T: HasValue + ?Sized> HasValue for Arc<T> {
+    fn has_value(&self) -> bool { (**self).has_value() }
+}
+
+impl<T> HasValue for Option<T> {
+    fn has_value(&self) -> bool { self.is_some() }
+}
+
+macro_rules! impl_has_value_for_has_length {
+    ($([$($generics:tt)*] $ty:ty),* $(,)?) => {
+        $(impl<$($generics)*> HasValue for $ty {
+            fn has_value(&self) -> bool { self.length(LengthMode::Bytes) > 0 }
+        })*
+    };
+}
+
+impl_has_value_for_has_length! {
+    [] str,
+    [] String,
+    ['a] Cow<'a, str>,
+    [T] [T],
+    [T, const N: usize] [T; N],
+    [T] Vec<T>,
+    [T] VecDeque<T>,
+    [T, S] HashSet<T, S>,
+    [T] BTreeSet<T>,
+    [K, V, S] HashMap<K, V, S>,
+    [K, V] BTreeMap<K, V>,
+}
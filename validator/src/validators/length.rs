@@ -0,0 +1,113 @@
+use crate::prelude::*;
+use crate::validators::has_length::HasLength;
+use crate::validators::length_mode::LengthMode;
+use std::borrow::Cow;
+
+pub trait LengthValidator<T: ?Sized>: Validator<T> {}
+
+/// Checks the length of strings and collections against optional `min`/`max`
+/// bounds or an exact `equal` length, e.g.
+/// `#[validate(Length(min = 3, max = 10))]` or `#[validate(Length(equal = 2))]`.
+#[derive(Default)]
+pub struct Length {
+    pub min: Option<u32>,
+    pub max: Option<u32>,
+    pub equal: Option<u32>,
+    pub mode: LengthMode,
+    pub message: Option<Cow<'static, str>>,
+}
+
+impl Length {
+    pub fn new(min: u32, max: u32) -> Self { Self::default().min(min).max(max) }
+    pub fn min(mut self, min: u32) -> Self {
+        self.min = Some(min);
+        self
+    }
+    pub fn max(mut self, max: u32) -> Self {
+        self.max = Some(max);
+        self
+    }
+    pub fn equal(mut self, equal: u32) -> Self {
+        self.equal = Some(equal);
+        self
+    }
+    pub fn mode(mut self, mode: LengthMode) -> Self {
+        self.mode = mode;
+        self
+    }
+    pub fn with_message(mut self, msg: impl Into<Cow<'static, str>>) -> Self {
+        self.message = Some(msg.into());
+        self
+    }
+}
+
+impl<T: HasLength + ?Sized> Validator<T> for Length {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        let len = value.length(self.mode);
+        let too_short = self.min.is_some_and(|min| len < min as usize);
+        let too_long = self.max.is_some_and(|max| len > max as usize);
+        let not_equal = self.equal.is_some_and(|equal| len != equal as usize);
+        if !(too_short || too_long || not_equal) {
+            return Ok(());
+        }
+
+        let mut err = ValidationError::new("length", "")
+            .with_param("len", len.to_string())
+            .with_param("mode", self.mode.as_str());
+        for (key, limit) in [("min", self.min), ("max", self.max), ("equal", self.equal)] {
+            if let Some(limit) = limit {
+                err = err.with_param(key, limit.to_string());
+            }
+        }
+        let default_msg = match (self.equal, self.min, self.max) {
+            (Some(_), _, _) => "Length must be exactly {equal}",
+            (None, Some(_), Some(_)) => "Length must be between {min} and {max}",
+            (None, Some(_), None) => "Length must be at least {min}",
+            _ => "Length must be at most {max}",
+        };
+        err.message = self.message.clone().unwrap_or(default_msg.into());
+        Err(err.render())
+    }
+}
+
+impl<T: HasLength + ?Sized> LengthValidator<T> for Length {}
+
+impl crate::validators::ValidatorExt for Length {}
+
+#[cfg(feature = "json_schema")]
+impl crate::json_schema::JsonSchemaRule for Length {
+    fn extend_schema(&self, schema: &mut serde_json::Map<String, serde_json::Value>) {
+        let (min, max) = match self.equal {
+            Some(equal) => (Some(equal), Some(equal)),
+            None => (self.min, self.max),
+        };
+        let keywords = (crate::json_schema::length_keyword(schema, self.mode, false), crate::json_schema::length_keyword(schema, self.mode, true));
+        let (Some(min_keyword), Some(max_keyword)) = keywords else {
+            let mut rule = serde_json::json!({ "name": "Length", "mode": self.mode.as_str() });
+            for (key, bound) in [("min", self.min), ("max", self.max), ("equal", self.equal)] {
+                if let Some(bound) = bound {
+                    rule[key] = bound.into();
+                }
+            }
+            return crate::json_schema::__private::annotate(schema, rule);
+        };
+        if let Some(min) = min {
+            schema.insert(min_keyword.into(), min.into());
+        }
+        if let Some(max) = max {
+            schema.insert(max_keyword.into(), max.into());
+        }
+    }
+}
+
+impl Describe for Length {
+    fn describe(&self) -> RuleDescriptor {
+        let mut descriptor = RuleDescriptor::new("Length", "length").with_param("mode", self.mode.as_str());
+        for (key, bound) in [("min", self.min), ("max", self.max), ("equal", self.equal)] {
+            if let Some(bound) = bound {
+                descriptor = descriptor.with_param(key, bound.to_string());
+            }
+        }
+        descriptor.with_message(self.message.clone())
+    }
+}
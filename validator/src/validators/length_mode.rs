@@ -0,0 +1,38 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+/// How the length of a string is counted by the length validators.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LengthMode {
+    /// UTF-8 bytes (`str::len`), e.g. `"Müller"` has length 7.
+    #[default]
+    Bytes,
+    /// Unicode scalar values (`char`s), e.g. `"Müller"` has length 6.
+    Chars,
+    /// Extended grapheme clusters, i.e. user-perceived characters; an emoji
+    /// with skin tone modifier has length 1.
+    Graphemes,
+    /// UTF-16 code units, matching the `maxlength` attribute of HTML inputs.
+    Utf16,
+}
+
+impl LengthMode {
+    /// Count the length of `value` in this mode.
+    pub fn count(&self, value: &str) -> usize {
+        match self {
+            LengthMode::Bytes => value.len(),
+            LengthMode::Chars => value.chars().count(),
+            LengthMode::Graphemes => value.graphemes(true).count(),
+            LengthMode::Utf16 => value.encode_utf16().count(),
+        }
+    }
+
+    /// Name reported in the `mode` param of length errors.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LengthMode::Bytes => "bytes",
+            LengthMode::Chars => "chars",
+            LengthMode::Graphemes => "graphemes",
+            LengthMode::Utf16 => "utf16",
+        }
+    }
+}
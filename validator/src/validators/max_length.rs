@@ -1,32 +1,42 @@
 use crate::prelude::*;
+use crate::validators::has_length::HasLength;
+use crate::validators::length_mode::LengthMode;
 use std::borrow::Cow;
 
 pub trait MaxLengthValidator: Validator<String> {}
 
 pub struct MaxLength {
     pub limit: u32,
+    pub mode: LengthMode,
     pub message: Option<Cow<'static, str>>,
 }
 
 impl MaxLength {
-    pub fn new(max: u32) -> Self { Self { limit: max, message: None } }
+    pub fn new(max: u32) -> Self { Self { limit: max, mode: LengthMode::default(), message: None } }
+    pub fn mode(mut self, mode: LengthMode) -> Self {
+        self.mode = mode;
+        self
+    }
     pub fn with_message(mut self, msg: impl Into<Cow<'static, str>>) -> Self {
         self.message = Some(msg.into());
         self
     }
 }
 
-impl Default for MaxLength { fn default() -> Self { MaxLength { limit: u32::MAX, message: None } } }
+impl Default for MaxLength { fn default() -> Self { MaxLength { limit: u32::MAX, mode: LengthMode::default(), message: None } } }
 
-impl Validator<String> for MaxLength {
-    fn validate(&self, value: &String) -> Result<(), ValidationError> {
-        if (value.len() as u32) > self.limit {
-            let default_msg = format!("String too long (max {})", self.limit);
-            let msg = self.message.as_deref().unwrap_or(&default_msg);
+impl<T: HasLength + ?Sized> Validator<T> for MaxLength {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        let len = value.length(self.mode);
+        if len > self.limit as usize {
+            let default_msg = "String too long (max {limit})";
+            let msg = self.message.as_deref().unwrap_or(default_msg);
             Err(
                 ValidationError::new("max_length", msg.to_string())
                     .with_param("limit", self.limit.to_string())
-                    .with_param("len", value.len().to_string()),
+                    .with_param("len", len.to_string())
+                    .with_param("mode", self.mode.as_str())
+                    .render(),
             )
         } else {
             Ok(())
@@ -34,4 +44,30 @@ impl Validator<String> for MaxLength {
     }
 }
 
-impl MaxLengthValidator for MaxLength {}
\ No newline at end of file
+impl MaxLengthValidator for MaxLength {}
+
+impl crate::validators::ValidatorExt for MaxLength {}
+
+#[cfg(feature = "json_schema")]
+impl crate::json_schema::JsonSchemaRule for MaxLength {
+    fn extend_schema(&self, schema: &mut serde_json::Map<String, serde_json::Value>) {
+        match crate::json_schema::length_keyword(schema, self.mode, true) {
+            Some(keyword) => {
+                schema.insert(keyword.into(), self.limit.into());
+            }
+            None => crate::json_schema::__private::annotate(
+                schema,
+                serde_json::json!({ "name": "MaxLength", "limit": self.limit, "mode": self.mode.as_str() }),
+            ),
+        }
+    }
+}
+
+impl Describe for MaxLength {
+    fn describe(&self) -> RuleDescriptor {
+        RuleDescriptor::new("MaxLength", "max_length")
+            .with_param("limit", self.limit.to_string())
+            .with_param("mode", self.mode.as_str())
+            .with_message(self.message.clone())
+    }
+}
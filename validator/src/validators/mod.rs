@@ -4,13 +4,44 @@ pub mod email_validator;
 pub mod not_allowed_chars;
 #[cfg(feature = "positive")]
 pub mod positive_number_validator;
+#[cfg(feature = "negative")]
+pub mod negative_number_validator;
+#[cfg(feature = "non_zero")]
+pub mod non_zero;
+#[cfg(feature = "range")]
+pub mod range;
+pub mod number;
+pub mod combinator;
+pub mod length_mode;
+pub mod has_length;
+pub mod has_value;
+#[cfg(feature = "length")]
+pub mod length;
 #[cfg(feature = "max_length")]
 pub mod max_length;
 #[cfg(feature = "min_length")]
 pub mod min_length;
+#[cfg(feature = "cross_field")]
+pub mod must_match;
+#[cfg(feature = "cross_field")]
+pub mod field_comparison;
+#[cfg(feature = "cross_field")]
+pub mod required_if;
+#[cfg(feature = "cross_field")]
+pub mod mutually_exclusive;
 
 #[cfg(feature = "email")]
-pub use email_validator::Email;
+pub use email_validator::{Email, EmailMode};
+#[cfg(feature = "cross_field")]
+pub use field_comparison::{GreaterThanField, LessThanField};
+pub use combinator::{AllOf, And, AnyOf, DescribeList, Map, Not, Optional, Or, ValidatorExt, ValidatorList};
+#[cfg(feature = "json_schema")]
+pub use combinator::JsonSchemaList;
+pub use has_length::HasLength;
+pub use has_value::HasValue;
+#[cfg(feature = "length")]
+pub use length::Length;
+pub use length_mode::LengthMode;
 #[cfg(feature = "max_length")]
 pub use max_length::MaxLength;
 #[cfg(feature = "min_length")]
@@ -18,4 +49,16 @@ pub use min_length::MinLength;
 #[cfg(feature = "not_allowed_chars")]
 pub use not_allowed_chars::NotAllowedChars;
 #[cfg(feature = "positive")]
-pub use positive_number_validator::Positive;
\ No newline at end of file
+pub use positive_number_validator::Positive;
+#[cfg(feature = "negative")]
+pub use negative_number_validator::Negative;
+#[cfg(feature = "non_zero")]
+pub use non_zero::NonZero;
+#[cfg(feature = "range")]
+pub use range::Range;
+#[cfg(feature = "cross_field")]
+pub use must_match::MustMatch;
+#[cfg(feature = "cross_field")]
+pub use mutually_exclusive::MutuallyExclusive;
+#[cfg(feature = "cross_field")]
+pub use required_if::{RequiredIf, RequiredUnless};
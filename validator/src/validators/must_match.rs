@@ -0,0 +1,48 @@
+use crate::prelude::*;
+use std::borrow::Cow;
+
+/// Checks that a field equals another field of the same struct, e.g. a
+/// password confirmation:
+/// ```
+/// use validator::prelude::*;
+/// use validator::validators::MustMatch;
+///
+/// #[derive(validator::Validate)]
+/// struct Signup {
+///     password: String,
+///     #[validate(MustMatch(other = "password"))]
+///     password_confirm: String,
+/// }
+///
+/// let signup = Signup { password: "hunter2".into(), password_confirm: "hunter3".into() };
+/// assert_eq!(signup.validate().unwrap_err().code, "must_match");
+/// ```
+#[derive(Default)]
+pub struct MustMatch {
+    pub message: Option<Cow<'static, str>>,
+}
+
+impl MustMatch {
+    pub fn with_message(mut self, msg: impl Into<Cow<'static, str>>) -> Self {
+        self.message = Some(msg.into());
+        self
+    }
+}
+
+impl<T: PartialEq<U> + ?Sized, U: ?Sized> CrossFieldValidator<T, U> for MustMatch {
+    fn validate(&self, value: &T, other: &U) -> Result<(), ValidationError> {
+        if value == other {
+            Ok(())
+        } else {
+            let default_msg = "Value must match {other}";
+            let msg = self.message.as_deref().unwrap_or(default_msg);
+            Err(ValidationError::new("must_match", msg.to_string()))
+        }
+    }
+}
+
+impl Describe for MustMatch {
+    fn describe(&self) -> RuleDescriptor {
+        RuleDescriptor::new("MustMatch", "must_match").with_message(self.message.clone())
+    }
+}
@@ -0,0 +1,49 @@
+use crate::prelude::*;
+use crate::validators::has_value::HasValue;
+use std::borrow::Cow;
+
+/// Rejects a value (see [`HasValue`]) when another field has one as well:
+/// ```
+/// use validator::prelude::*;
+/// use validator::validators::MutuallyExclusive;
+///
+/// #[derive(validator::Validate)]
+/// struct Contact {
+///     #[validate(MutuallyExclusive(other = "phone"))]
+///     email: Option<String>,
+///     phone: Option<String>,
+/// }
+///
+/// assert!(Contact { email: Some("jo@example.com".into()), phone: None }.validate().is_ok());
+/// let both = Contact { email: Some("jo@example.com".into()), phone: Some("555".into()) };
+/// assert!(both.validate().is_err());
+/// ```
+#[derive(Default)]
+pub struct MutuallyExclusive {
+    pub message: Option<Cow<'static, str>>,
+}
+
+impl MutuallyExclusive {
+    pub fn with_message(mut self, msg: impl Into<Cow<'static, str>>) -> Self {
+        self.message = Some(msg.into());
+        self
+    }
+}
+
+impl<T: HasValue + ?Sized, U: HasValue + ?Sized> CrossFieldValidator<T, U> for MutuallyExclusive {
+    fn validate(&self, value: &T, other: &U) -> Result<(), ValidationError> {
+        if value.has_value() && other.has_value() {
+            let default_msg = "Value cannot be combined with {other}";
+            let msg = self.message.as_deref().unwrap_or(default_msg);
+            Err(ValidationError::new("mutually_exclusive", msg.to_string()))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl Describe for MutuallyExclusive {
+    fn describe(&self) -> RuleDescriptor {
+        RuleDescriptor::new("MutuallyExclusive", "mutually_exclusive").with_message(self.message.clone())
+    }
+}
@@ -0,0 +1,46 @@
+use crate::prelude::*;
+use crate::validators::number::Number;
+use std::borrow::Cow;
+
+pub trait NegativeNumberValidator<T>: Validator<T> {}
+
+#[derive(Default)]
+pub struct Negative {
+    pub message: Option<Cow<'static, str>>,
+}
+
+impl Negative {
+    pub fn with_message(mut self, msg: impl Into<Cow<'static, str>>) -> Self {
+        self.message = Some(msg.into());
+        self
+    }
+}
+
+impl<T: Number> Validator<T> for Negative {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        if !value.is_nan() && *value < T::zero() {
+            Ok(())
+        } else {
+            let default_msg = "Value must be a negative number";
+            let msg = self.message.as_deref().unwrap_or(default_msg);
+            Err(ValidationError::new("negative", msg.to_string()).with_param("value", value.to_string()).render())
+        }
+    }
+}
+
+impl<T: Number> NegativeNumberValidator<T> for Negative {}
+
+impl crate::validators::ValidatorExt for Negative {}
+
+#[cfg(feature = "json_schema")]
+impl crate::json_schema::JsonSchemaRule for Negative {
+    fn extend_schema(&self, schema: &mut serde_json::Map<String, serde_json::Value>) {
+        schema.insert("exclusiveMaximum".into(), 0.into());
+    }
+}
+
+impl Describe for Negative {
+    fn describe(&self) -> RuleDescriptor {
+        RuleDescriptor::new("Negative", "negative").with_message(self.message.clone())
+    }
+}
@@ -0,0 +1,46 @@
+use crate::prelude::*;
+use crate::validators::number::Number;
+use std::borrow::Cow;
+
+pub trait NonZeroValidator<T>: Validator<T> {}
+
+#[derive(Default)]
+pub struct NonZero {
+    pub message: Option<Cow<'static, str>>,
+}
+
+impl NonZero {
+    pub fn with_message(mut self, msg: impl Into<Cow<'static, str>>) -> Self {
+        self.message = Some(msg.into());
+        self
+    }
+}
+
+impl<T: Number> Validator<T> for NonZero {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        if !value.is_nan() && *value != T::zero() {
+            Ok(())
+        } else {
+            let default_msg = "Value must not be zero";
+            let msg = self.message.as_deref().unwrap_or(default_msg);
+            Err(ValidationError::new("non_zero", msg.to_string()).with_param("value", value.to_string()).render())
+        }
+    }
+}
+
+impl<T: Number> NonZeroValidator<T> for NonZero {}
+
+impl crate::validators::ValidatorExt for NonZero {}
+
+#[cfg(feature = "json_schema")]
+impl crate::json_schema::JsonSchemaRule for NonZero {
+    fn extend_schema(&self, schema: &mut serde_json::Map<String, serde_json::Value>) {
+        schema.insert("not".into(), serde_json::json!({ "const": 0 }));
+    }
+}
+
+impl Describe for NonZero {
+    fn describe(&self) -> RuleDescriptor {
+        RuleDescriptor::new("NonZero", "non_zero").with_message(self.message.clone())
+    }
+}
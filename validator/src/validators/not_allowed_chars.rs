@@ -2,6 +2,7 @@ use crate::prelude::*;
 use std::borrow::Cow;
 
 pub trait NotAllowedCharsValidator: Validator<String> {}
+#[derive(Default)]
 pub struct NotAllowedChars {
     forbidden: Vec<String>,
     pub message: Option<Cow<'static, str>>,
@@ -22,17 +23,16 @@ impl NotAllowedChars {
     }
 }
 
-impl Default for NotAllowedChars { fn default() -> Self { NotAllowedChars { forbidden: Vec::default(), message: None } } }
-
-impl Validator<String> for NotAllowedChars {
-    fn validate(&self, value: &String) -> Result<(), ValidationError> {
+impl<T: AsRef<str> + ?Sized> Validator<T> for NotAllowedChars {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
         for c in self.forbidden.iter() {
-            if !value.contains(c) {} else {
+            if !value.as_ref().contains(c) {} else {
                 let default_msg = "Value contains not allowed string";
                 let msg = self.message.as_deref().unwrap_or(default_msg);
                 return Err(
                     ValidationError::new("not_allowed_chars", msg.to_string())
                         .with_param("hit", c.clone())
+                        .render()
                 );
             }
         }
@@ -40,4 +40,39 @@ impl Validator<String> for NotAllowedChars {
     }
 }
 
-impl NotAllowedCharsValidator for NotAllowedChars {}
\ No newline at end of file
+impl NotAllowedCharsValidator for NotAllowedChars {}
+
+impl crate::validators::ValidatorExt for NotAllowedChars {}
+
+/// Matches any of the forbidden strings: `not: { pattern: "a|\\$" }`.
+#[cfg(feature = "json_schema")]
+impl crate::json_schema::JsonSchemaRule for NotAllowedChars {
+    fn extend_schema(&self, schema: &mut serde_json::Map<String, serde_json::Value>) {
+        if self.forbidden.is_empty() {
+            return;
+        }
+        let pattern = self.forbidden.iter().map(|s| escape_regex(s)).collect::<Vec<_>>().join("|");
+        schema.insert("not".into(), serde_json::json!({ "pattern": pattern }));
+    }
+}
+
+/// Escape the characters with a meaning in (ECMA 262) regular expressions.
+#[cfg(feature = "json_schema")]
+fn escape_regex(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        if "\\^$.|?*+()[]{}/".contains(c) {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+impl Describe for NotAllowedChars {
+    fn describe(&self) -> RuleDescriptor {
+        RuleDescriptor::new("NotAllowedChars", "not_allowed_chars")
+            .with_param("forbidden", self.forbidden.join(", "))
+            .with_message(self.message.clone())
+    }
+}
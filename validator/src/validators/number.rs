@@ -0,0 +1,33 @@
+use std::fmt::Display;
+
+/// Numeric types supported by the number validators (`Range`, `Positive`,
+/// `Negative`, `NonZero`).
+///
+/// Implemented for all primitive integers and floats. Implement it for other
+/// numeric types (e.g. a decimal type) to use them with the same validators.
+pub trait Number: PartialOrd + Display {
+    /// The zero value of the type.
+    fn zero() -> Self;
+
+    /// `true` for values that are not a number (float `NaN`). Such values are
+    /// always rejected.
+    fn is_nan(&self) -> bool { false }
+}
+
+macro_rules! impl_number_for_int {
+    ($($ty:ty),*) => {
+        $(impl Number for $ty { fn zero() -> Self { 0 } })*
+    };
+}
+
+macro_rules! impl_number_for_float {
+    ($($ty:ty),*) => {
+        $(impl Number for $ty {
+            fn zero() -> Self { 0.0 }
+            fn is_nan(&self) -> bool { <$ty>::is_nan(*self) }
+        })*
+    };
+}
+
+impl_number_for_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_number_for_float!(f32, f64);
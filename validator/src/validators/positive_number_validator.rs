@@ -1,14 +1,14 @@
 use crate::prelude::*;
+use crate::validators::number::Number;
 use std::borrow::Cow;
 
-pub trait PositiveNumberValidator: Validator<i32> {}
+pub trait PositiveNumberValidator<T>: Validator<T> {}
 
+#[derive(Default)]
 pub struct Positive {
     pub message: Option<Cow<'static, str>>,
 }
 
-impl Default for Positive { fn default() -> Self { Positive { message: None } } }
-
 impl Positive {
     pub fn with_message(mut self, msg: impl Into<Cow<'static, str>>) -> Self {
         self.message = Some(msg.into());
@@ -16,16 +16,31 @@ impl Positive {
     }
 }
 
-impl Validator<i32> for Positive {
-    fn validate(&self, value: &i32) -> Result<(), ValidationError> {
-        if *value > 0 {
+impl<T: Number> Validator<T> for Positive {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        if !value.is_nan() && *value > T::zero() {
             Ok(())
         } else {
             let default_msg = "Value must be a positive number";
             let msg = self.message.as_deref().unwrap_or(default_msg);
-            Err(ValidationError::new("positive", msg.to_string()))
+            Err(ValidationError::new("positive", msg.to_string()).with_param("value", value.to_string()).render())
         }
     }
 }
 
-impl PositiveNumberValidator for Positive {}
\ No newline at end of file
+impl<T: Number> PositiveNumberValidator<T> for Positive {}
+
+impl crate::validators::ValidatorExt for Positive {}
+
+#[cfg(feature = "json_schema")]
+impl crate::json_schema::JsonSchemaRule for Positive {
+    fn extend_schema(&self, schema: &mut serde_json::Map<String, serde_json::Value>) {
+        schema.insert("exclusiveMinimum".into(), 0.into());
+    }
+}
+
+impl Describe for Positive {
+    fn describe(&self) -> RuleDescriptor {
+        RuleDescriptor::new("Positive", "positive").with_message(self.message.clone())
+    }
+}
@@ -0,0 +1,133 @@
+use crate::prelude::*;
+use crate::validators::number::Number;
+use std::borrow::Cow;
+use std::ops::Bound;
+
+pub trait RangeValidator<T>: Validator<T> {}
+
+/// Checks that a number lies within optional lower and upper bounds.
+///
+/// Bounds are inclusive by default; use `exclusive_min`/`exclusive_max` for
+/// strict bounds. `NaN` is always rejected. In the derive annotation:
+/// `#[validate(Range(min = 1, max = 100))]`, `#[validate(Range(exclusive_min = 0.0))]`
+/// or positional `#[validate(Range(1, 100))]`.
+pub struct Range<T> {
+    pub min: Bound<T>,
+    pub max: Bound<T>,
+    pub message: Option<Cow<'static, str>>,
+}
+
+impl<T> Range<T> {
+    pub fn new(min: T, max: T) -> Self {
+        Self { min: Bound::Included(min), max: Bound::Included(max), message: None }
+    }
+    pub fn min(mut self, min: T) -> Self {
+        self.min = Bound::Included(min);
+        self
+    }
+    pub fn max(mut self, max: T) -> Self {
+        self.max = Bound::Included(max);
+        self
+    }
+    pub fn exclusive_min(mut self, min: T) -> Self {
+        self.min = Bound::Excluded(min);
+        self
+    }
+    pub fn exclusive_max(mut self, max: T) -> Self {
+        self.max = Bound::Excluded(max);
+        self
+    }
+    pub fn with_message(mut self, msg: impl Into<Cow<'static, str>>) -> Self {
+        self.message = Some(msg.into());
+        self
+    }
+}
+
+impl<T> Default for Range<T> {
+    fn default() -> Self { Range { min: Bound::Unbounded, max: Bound::Unbounded, message: None } }
+}
+
+impl<T: Number> Validator<T> for Range<T> {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        let above_min = match &self.min {
+            Bound::Included(min) => value >= min,
+            Bound::Excluded(min) => value > min,
+            Bound::Unbounded => true,
+        };
+        let below_max = match &self.max {
+            Bound::Included(max) => value <= max,
+            Bound::Excluded(max) => value < max,
+            Bound::Unbounded => true,
+        };
+        if !value.is_nan() && above_min && below_max {
+            return Ok(());
+        }
+
+        let mut err = ValidationError::new("range", "").with_param("value", value.to_string());
+        let mut parts = Vec::new();
+        match &self.min {
+            Bound::Included(min) => {
+                err = err.with_param("min", min.to_string());
+                parts.push("greater than or equal to {min}");
+            }
+            Bound::Excluded(min) => {
+                err = err.with_param("min", min.to_string()).with_param("exclusive_min", "true");
+                parts.push("greater than {min}");
+            }
+            Bound::Unbounded => {}
+        }
+        match &self.max {
+            Bound::Included(max) => {
+                err = err.with_param("max", max.to_string());
+                parts.push("less than or equal to {max}");
+            }
+            Bound::Excluded(max) => {
+                err = err.with_param("max", max.to_string()).with_param("exclusive_max", "true");
+                parts.push("less than {max}");
+            }
+            Bound::Unbounded => {}
+        }
+        let default_msg = if value.is_nan() || parts.is_empty() {
+            "Value must be a number".to_string()
+        } else {
+            format!("Value must be {}", parts.join(" and "))
+        };
+        err.message = self.message.clone().unwrap_or(default_msg.into());
+        Err(err.render())
+    }
+}
+
+impl<T: Number> RangeValidator<T> for Range<T> {}
+
+impl<T> crate::validators::ValidatorExt for Range<T> {}
+
+#[cfg(feature = "json_schema")]
+impl<T: Number> crate::json_schema::JsonSchemaRule for Range<T> {
+    fn extend_schema(&self, schema: &mut serde_json::Map<String, serde_json::Value>) {
+        use crate::json_schema::json_number;
+        match &self.min {
+            Bound::Included(min) => schema.insert("minimum".into(), json_number(min)),
+            Bound::Excluded(min) => schema.insert("exclusiveMinimum".into(), json_number(min)),
+            Bound::Unbounded => None,
+        };
+        match &self.max {
+            Bound::Included(max) => schema.insert("maximum".into(), json_number(max)),
+            Bound::Excluded(max) => schema.insert("exclusiveMaximum".into(), json_number(max)),
+            Bound::Unbounded => None,
+        };
+    }
+}
+
+impl<T: Number> Describe for Range<T> {
+    fn describe(&self) -> RuleDescriptor {
+        let mut descriptor = RuleDescriptor::new("Range", "range");
+        for (bound, inclusive, exclusive) in [(&self.min, "min", "exclusive_min"), (&self.max, "max", "exclusive_max")] {
+            match bound {
+                Bound::Included(value) => descriptor = descriptor.with_param(inclusive, value.to_string()),
+                Bound::Excluded(value) => descriptor = descriptor.with_param(exclusive, value.to_string()),
+                Bound::Unbounded => {}
+            }
+        }
+        descriptor.with_message(self.message.clone())
+    }
+}
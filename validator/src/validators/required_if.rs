@@ -0,0 +1,120 @@
+use crate::prelude::*;
+use crate::validators::has_value::HasValue;
+use std::borrow::Cow;
+
+/// Requires a value (see [`HasValue`]) when another field equals `eq`:
+/// ```
+/// use validator::prelude::*;
+/// use validator::validators::RequiredIf;
+///
+/// #[derive(validator::Validate)]
+/// struct Address {
+///     country: String,
+///     #[validate(RequiredIf(field = "country", eq = "US"))]
+///     state: Option<String>,
+/// }
+///
+/// assert!(Address { country: "FR".into(), state: None }.validate().is_ok());
+/// assert!(Address { country: "US".into(), state: None }.validate().is_err());
+/// ```
+/// Without `eq` the field is never required.
+pub struct RequiredIf<E> {
+    pub eq: Option<E>,
+    pub message: Option<Cow<'static, str>>,
+}
+
+impl<E> Default for RequiredIf<E> {
+    fn default() -> Self { RequiredIf { eq: None, message: None } }
+}
+
+impl<E> RequiredIf<E> {
+    pub fn new(eq: E) -> Self { Self::default().eq(eq) }
+    pub fn eq(mut self, eq: E) -> Self {
+        self.eq = Some(eq);
+        self
+    }
+    pub fn with_message(mut self, msg: impl Into<Cow<'static, str>>) -> Self {
+        self.message = Some(msg.into());
+        self
+    }
+}
+
+impl<T: HasValue + ?Sized, U: PartialEq<E> + ?Sized, E> CrossFieldValidator<T, U> for RequiredIf<E> {
+    fn validate(&self, value: &T, other: &U) -> Result<(), ValidationError> {
+        let required = self.eq.as_ref().is_some_and(|eq| other == eq);
+        required_error(required && !value.has_value(), self.message.as_deref())
+    }
+}
+
+/// Requires a value (see [`HasValue`]) unless another field equals `eq`:
+/// ```
+/// use validator::prelude::*;
+/// use validator::validators::RequiredUnless;
+///
+/// #[derive(validator::Validate)]
+/// struct Checkout {
+///     payment: String,
+///     #[validate(RequiredUnless(field = "payment", eq = "cash"))]
+///     card_number: Option<String>,
+/// }
+///
+/// assert!(Checkout { payment: "cash".into(), card_number: None }.validate().is_ok());
+/// assert!(Checkout { payment: "card".into(), card_number: None }.validate().is_err());
+/// ```
+/// Without `eq` the field is always required.
+pub struct RequiredUnless<E> {
+    pub eq: Option<E>,
+    pub message: Option<Cow<'static, str>>,
+}
+
+impl<E> Default for RequiredUnless<E> {
+    fn default() -> Self { RequiredUnless { eq: None, message: None } }
+}
+
+impl<E> RequiredUnless<E> {
+    pub fn new(eq: E) -> Self { Self::default().eq(eq) }
+    pub fn eq(mut self, eq: E) -> Self {
+        self.eq = Some(eq);
+        self
+    }
+    pub fn with_message(mut self, msg: impl Into<Cow<'static, str>>) -> Self {
+        self.message = Some(msg.into());
+        self
+    }
+}
+
+impl<T: HasValue + ?Sized, U: PartialEq<E> + ?Sized, E> CrossFieldValidator<T, U> for RequiredUnless<E> {
+    fn validate(&self, value: &T, other: &U) -> Result<(), ValidationError> {
+        let exempt = self.eq.as_ref().is_some_and(|eq| other == eq);
+        required_error(!exempt && !value.has_value(), self.message.as_deref())
+    }
+}
+
+fn required_error(missing: bool, message: Option<&str>) -> Result<(), ValidationError> {
+    if missing {
+        let msg = message.unwrap_or("Value is required");
+        Err(ValidationError::new("required", msg.to_string()))
+    } else {
+        Ok(())
+    }
+}
+
+impl<E: std::fmt::Display> Describe for RequiredIf<E> {
+    fn describe(&self) -> RuleDescriptor {
+        describe_required("RequiredIf", &self.eq, &self.message)
+    }
+}
+
+impl<E: std::fmt::Display> Describe for RequiredUnless<E> {
+    fn describe(&self) -> RuleDescriptor {
+        describe_required("RequiredUnless", &self.eq, &self.message)
+    }
+}
+
+fn describe_required<E: std::fmt::Display>(name: &'static str, eq: &Option<E>, message: &Option<Cow<'static, str>>) -> RuleDescriptor {
+    let descriptor = RuleDescriptor::new(name, "required").with_message(message.clone());
+    match eq {
+        Some(eq) => descriptor.with_param("eq", eq.to_string()),
+        None => descriptor,
+    }
+}
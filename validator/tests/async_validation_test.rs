@@ -1,6 +1,9 @@
 #![cfg(feature = "async")]
 use validator::prelude::*;
 use validator::validators::{MaxLength, MinLength};
+use validator::futures::FutureExt;
+use std::future::Future;
+use std::pin::Pin;
 
 fn run_async<F: core::future::Future<Output = ()>>(fut: F) {
     validator::futures::executor::block_on(fut)
@@ -37,4 +40,414 @@ fn max_length_async() {
         assert_eq!(err.params.get("limit").map(|s| s.as_ref()), Some("10"));
         assert_eq!(err.params.get("len").map(|s| s.as_ref()), Some("13"));
     })
-}
\ No newline at end of file
+}
+#[derive(validator::Validate)]
+struct Address {
+    #[validate(MinLength(3))]
+    street: String,
+}
+
+#[derive(validator::Validate)]
+struct User {
+    #[validate(MaxLength(10))]
+    name: String,
+    #[validate(nested)]
+    address: Address,
+}
+
+#[test]
+fn nested_async() {
+    run_async(async {
+        let u = User {
+            name: "Test".to_string(),
+            address: Address { street: "Ma".to_string() },
+        };
+        let err = u.validate_async().await.unwrap_err();
+        assert_eq!(err.code, "min_length");
+        let errs = u.complete_validate_async().await.unwrap_err();
+        assert_eq!(errs.get("address.street").map(|v| v.len()), Some(1));
+    })
+}
+
+#[derive(validator::Validate)]
+struct Team {
+    #[validate(each(MinLength(3)))]
+    names: Vec<String>,
+    #[validate(nested)]
+    addresses: Vec<Address>,
+}
+
+#[test]
+fn collections_async() {
+    run_async(async {
+        let t = Team {
+            names: vec!["Anna".to_string(), "Bo".to_string()],
+            addresses: vec![Address { street: "Ma".to_string() }],
+        };
+        let errs = t.complete_validate_async().await.unwrap_err();
+        assert!(errs.contains_key("names[1]"));
+        assert!(errs.contains_key("addresses[0].street"));
+    })
+}
+
+#[derive(validator::Validate)]
+#[validate(schema(function = "check_street_sync"), schema(function = "check_street_available", async))]
+struct Registration {
+    #[validate(MaxLength(10))]
+    name: String,
+    street: String,
+}
+
+fn check_street_sync(r: &Registration) -> Result<(), ValidationError> {
+    if r.street.is_empty() { Err(ValidationError::new("required", "street is required")) } else { Ok(()) }
+}
+
+async fn check_street_available(r: &Registration) -> Result<(), ValidationError> {
+    if r.street == "Taken Street" { Err(ValidationError::new("taken", "street is taken")) } else { Ok(()) }
+}
+
+#[test]
+fn schema_async() {
+    let r = Registration { name: "Test".to_string(), street: "Taken Street".to_string() };
+    // async hooks only run in `ValidateAsync`
+    assert!(r.validate().is_ok());
+    run_async(async {
+        let err = r.validate_async().await.unwrap_err();
+        assert_eq!(err.code, "taken");
+
+        let r = Registration { name: "Much too long".to_string(), street: String::new() };
+        let errs = r.complete_validate_async().await.unwrap_err();
+        assert!(errs.contains_key("name"));
+        assert_eq!(errs.errors()[0].code, "required");
+    })
+}
+
+async fn check_username_free(name: &str) -> Result<(), ValidationError> {
+    if name == "taken" { Err(ValidationError::new("taken", "{field} is taken")) } else { Ok(()) }
+}
+
+fn check_username_chars(name: &str) -> Result<(), ValidationError> {
+    if name.chars().all(char::is_alphanumeric) { Ok(()) } else { Err(ValidationError::new("chars", "Invalid characters")) }
+}
+
+#[derive(validator::Validate)]
+struct Account {
+    #[validate(custom(function = "check_username_chars"), custom(function = "check_username_free", async, code = "username_taken"))]
+    username: String,
+}
+
+#[test]
+fn custom_async() {
+    let a = Account { username: "taken".to_string() };
+    // async custom functions only run in `ValidateAsync`
+    assert!(a.validate().is_ok());
+    run_async(async {
+        let err = a.validate_async().await.unwrap_err();
+        assert_eq!(err.code, "username_taken");
+        assert_eq!(err.message, "username is taken");
+
+        let a = Account { username: "no way".to_string() };
+        let errs = a.complete_validate_async().await.unwrap_err();
+        assert_eq!(errs.get("username").map(|v| v[0].code.as_ref()), Some("chars"));
+    })
+}
+
+struct Db {
+    taken: Vec<&'static str>,
+}
+
+#[derive(Default)]
+struct Unique;
+
+impl ContextValidator<String, Db> for Unique {
+    fn validate_with(&self, value: &String, db: &Db) -> Result<(), ValidationError> {
+        if db.taken.contains(&value.as_str()) { Err(ValidationError::new("unique", "{field} is taken")) } else { Ok(()) }
+    }
+}
+
+async fn check_not_taken(name: &str, db: &Db) -> Result<(), ValidationError> {
+    if db.taken.contains(&name) { Err(ValidationError::new("taken", "Name is taken")) } else { Ok(()) }
+}
+
+#[derive(validator::Validate)]
+#[validate(context = "Db")]
+struct NewUser {
+    #[validate(MaxLength(10), Unique)]
+    login: String,
+    #[validate(custom(function = "check_not_taken", async, use_context))]
+    display_name: String,
+    #[validate(nested)]
+    address: Address,
+}
+
+#[test]
+fn context_async() {
+    let db = Db { taken: vec!["root"] };
+    assert!(Unique.validate_with_async(&"jo".to_string(), &db).now_or_never().unwrap().is_ok());
+    run_async(async {
+        let u = NewUser {
+            login: "jo".to_string(),
+            display_name: "Jo".to_string(),
+            address: Address { street: "Main".to_string() },
+        };
+        assert!(u.validate_with_async(&db).await.is_ok());
+
+        let u = NewUser {
+            login: "root".to_string(),
+            display_name: "root".to_string(),
+            address: Address { street: "Ma".to_string() },
+        };
+        let err = u.validate_with_async(&db).await.unwrap_err();
+        assert_eq!(err.message, "login is taken");
+        let errs = u.complete_validate_with_async(&db).await.unwrap_err();
+        assert_eq!(errs.get("display_name").map(|v| v[0].code.as_ref()), Some("taken"));
+        assert!(errs.contains_key("address.street"));
+    })
+}
+
+#[derive(validator::Validate)]
+struct Chain<'a> {
+    #[validate(MinLength(2))]
+    name: String,
+    #[validate(nested)]
+    next: Vec<&'a Chain<'a>>,
+}
+
+#[test]
+fn cycles_async() {
+    run_async(async {
+        let mut root = Chain { name: "x".into(), next: vec![] };
+        let rp = &root as *const Chain;
+        unsafe { root.next.push(&*rp) }
+
+        let err = root.complete_validate_async().await.unwrap_err();
+        assert_eq!(err.to_string(), "name: String too short (min 2)");
+        let mut state = ValidationState::new().report_cycles(true);
+        let err = root.complete_validate_async_in(&mut state).await.unwrap_err();
+        assert_eq!(err.get("next[0]").map(|v| v[0].code.as_ref()), Some("cycle"));
+    })
+}
+
+#[test]
+fn options_async() {
+    run_async(async {
+        let chain = Chain { name: "x".into(), next: vec![] };
+        let mut state = ValidationState::with_options(ValidationOptions::new().skip_fields(["name"]));
+        assert!(chain.complete_validate_async_in(&mut state).await.is_ok());
+        let mut state = ValidationState::with_options(ValidationOptions::new().max_errors(0));
+        assert!(chain.complete_validate_async_in(&mut state).await.is_ok());
+    })
+}
+
+type Signal<T> = std::cell::RefCell<Option<T>>;
+
+async fn wait_for_sibling(rx: &Signal<validator::futures::channel::oneshot::Receiver<()>>) -> Result<(), ValidationError> {
+    let rx = rx.borrow_mut().take().expect("validated once");
+    rx.await.map_err(|_| ValidationError::new("closed", "sibling did not run"))
+}
+
+async fn notify_sibling(tx: &Signal<validator::futures::channel::oneshot::Sender<()>>) -> Result<(), ValidationError> {
+    let _ = tx.borrow_mut().take().expect("validated once").send(());
+    Ok(())
+}
+
+#[derive(validator::Validate)]
+struct Handshake {
+    #[validate(custom(function = "wait_for_sibling", async))]
+    first: Signal<validator::futures::channel::oneshot::Receiver<()>>,
+    #[validate(custom(function = "notify_sibling", async))]
+    second: Signal<validator::futures::channel::oneshot::Sender<()>>,
+}
+
+fn handshake() -> Handshake {
+    let (tx, rx) = validator::futures::channel::oneshot::channel();
+    Handshake { first: Some(rx).into(), second: Some(tx).into() }
+}
+
+#[test]
+fn fields_run_concurrently() {
+    run_async(async {
+        assert!(handshake().complete_validate_async().await.is_ok());
+
+        // one field at a time, the first field waits until it times out
+        let options = ValidationOptions::new().max_concurrency(1).timeout(std::time::Duration::from_millis(50));
+        let err = handshake().complete_validate_async_in(&mut ValidationState::with_options(options)).await.unwrap_err();
+        assert_eq!(err.get("first").map(|v| v[0].code.as_ref()), Some("timeout"));
+    })
+}
+
+async fn never_done(_: &str) -> Result<(), ValidationError> {
+    validator::futures::future::pending().await
+}
+
+#[derive(validator::Validate)]
+struct Lookup {
+    #[validate(MinLength(3), custom(function = "never_done", async))]
+    vat: String,
+    #[validate(MinLength(3))]
+    name: String,
+}
+
+#[test]
+fn timeout_per_validator() {
+    run_async(async {
+        let lookup = Lookup { vat: "DE1".into(), name: "x".into() };
+        let options = ValidationOptions::new().timeout(std::time::Duration::from_millis(20));
+        let err = lookup.complete_validate_async_in(&mut ValidationState::with_options(options)).await.unwrap_err();
+        assert_eq!(err.to_string(), "name: String too short (min 3)\nvat: Validation timed out after 20 ms");
+        assert_eq!(err.get("vat").unwrap()[0].params.get("field").map(|f| f.as_ref()), Some("vat"));
+    })
+}
+
+#[test]
+fn timeout_skips_rules_done_right_away() {
+    run_async(async {
+        let ready = async { Err(ValidationError::new("ready", "Done")) };
+        let err = validator::concurrency::timeout(Some(std::time::Duration::ZERO), ready).await.unwrap_err();
+        assert_eq!(err.code, "ready");
+
+        let pending = validator::futures::future::pending();
+        let err = validator::concurrency::timeout(Some(std::time::Duration::ZERO), pending).await.unwrap_err();
+        assert_eq!(err.code, "timeout");
+    })
+}
+
+#[derive(validator::Validate)]
+struct Order {
+    #[validate(MinLength(3))]
+    id: String,
+    #[validate(nested)]
+    lines: Vec<Chain<'static>>,
+    #[validate(each(MinLength(2)), MaxLength(1))]
+    notes: Vec<String>,
+}
+
+#[test]
+fn concurrent_errors_match_sequential() {
+    run_async(async {
+        let order = Order {
+            id: "1".into(),
+            lines: vec![Chain { name: "ok".into(), next: vec![] }, Chain { name: "x".into(), next: vec![] }],
+            notes: vec!["a".into(), "bb".into()],
+        };
+        let concurrent = order.complete_validate_async().await.unwrap_err();
+        let sequential = order
+            .complete_validate_async_in(&mut ValidationState::with_options(ValidationOptions::new().max_concurrency(1)))
+            .await
+            .unwrap_err();
+        assert_eq!(concurrent, sequential);
+        assert_eq!(concurrent, order.complete_validate().unwrap_err());
+        let limited = order
+            .complete_validate_async_in(&mut ValidationState::with_options(ValidationOptions::new().max_errors(2)))
+            .await
+            .unwrap_err();
+        assert_eq!(limited.to_string(), "id: String too short (min 3)\nlines[1].name: String too short (min 2)");
+    })
+}
+
+#[derive(Default)]
+struct Slug;
+
+impl StaticAsyncValidator<String> for Slug {
+    async fn validate_static(&self, value: &String) -> Result<(), ValidationError> {
+        if value.chars().all(|c| c.is_ascii_lowercase() || c == '-') {
+            Ok(())
+        } else {
+            Err(ValidationError::new("slug", "{field} is not a slug"))
+        }
+    }
+}
+
+#[derive(validator::Validate)]
+struct Page {
+    #[validate(MinLength(3), MaxLength(20), Slug)]
+    slug: String,
+    #[validate(custom(function = "check_username_free", async))]
+    author: String,
+    #[validate(nested)]
+    address: Address,
+}
+
+fn assert_send<F: core::future::Future + Send>(fut: F) -> F { fut }
+
+#[test]
+fn static_async_futures_are_send() {
+    let page = Page { slug: "x".into(), author: "taken".into(), address: Address { street: "Ma".into() } };
+    let errs = std::thread::spawn(move || {
+        validator::futures::executor::block_on(async move { assert_send(page.complete_validate_static()).await })
+    })
+    .join()
+    .unwrap()
+    .unwrap_err();
+    assert_eq!(errs.get("slug").map(|v| v[0].code.as_ref()), Some("min_length"));
+    assert!(errs.contains_key("author"));
+    assert!(errs.contains_key("address.street"));
+
+    let db = Db { taken: vec!["root"] };
+    let u = NewUser { login: "root".into(), display_name: "Jo".into(), address: Address { street: "Main".into() } };
+    let err = assert_send(u.validate_with_static(&db)).now_or_never().unwrap().unwrap_err();
+    assert_eq!(err.code, "unique");
+}
+
+#[test]
+fn static_and_boxed_agree() {
+    run_async(async {
+        assert!(Slug.validate_static(&"hello-world".to_string()).await.is_ok());
+        let page = Page { slug: "Hello World".into(), author: "jo".into(), address: Address { street: "Main".into() } };
+        assert_eq!(page.validate_static().await.unwrap_err().code, "slug");
+        assert_eq!(page.validate_async().await.unwrap_err().code, "slug");
+
+        let page = Page { slug: "hello".into(), author: "jo".into(), address: Address { street: "Main".into() } };
+        assert!(page.validate_static().await.is_ok());
+        let page = Page { slug: "x".into(), author: "taken".into(), address: Address { street: "Ma".into() } };
+        assert_eq!(page.complete_validate_static().await, page.complete_validate_async().await);
+        let dynamic: &dyn ValidateAsync = &page;
+        assert_eq!(dynamic.validate_async().await.unwrap_err().code, "min_length");
+    })
+}
+
+#[derive(Default)]
+struct Remote;
+
+impl AsyncValidator<String> for Remote {
+    fn validate_async<'a>(&'a self, value: &'a String) -> Pin<Box<dyn Future<Output = Result<(), ValidationError>> + 'a>> {
+        Box::pin(async move {
+            if value.as_str() == "taken" { Err(ValidationError::new("remote", "{field} is taken")) } else { Ok(()) }
+        })
+    }
+}
+
+struct Inventory {
+    count: i64,
+}
+
+impl ValidateAsync for Inventory {
+    fn validate_async(&self) -> Pin<Box<dyn Future<Output = Result<(), ValidationError>> + '_>> {
+        Box::pin(async move {
+            if self.count < 0 { Err(ValidationError::new("inventory", "{field} is negative").with_field("count")) } else { Ok(()) }
+        })
+    }
+}
+
+#[derive(validator::Validate)]
+struct Listing {
+    #[validate(MinLength(2), Remote)]
+    name: String,
+    #[validate(nested)]
+    inventory: Inventory,
+}
+
+#[test]
+fn hand_written_async_traits_are_accepted() {
+    run_async(async {
+        let listing = Listing { name: "lamp".into(), inventory: Inventory { count: 1 } };
+        assert!(listing.validate_async().await.is_ok());
+        let listing = Listing { name: "taken".into(), inventory: Inventory { count: -1 } };
+        assert_eq!(listing.validate_async().await.unwrap_err().code, "remote");
+        let errs = listing.complete_validate_async().await.unwrap_err();
+        assert_eq!(errs.get("name").map(|v| v[0].code.as_ref()), Some("remote"));
+        assert_eq!(errs.get("inventory").map(|v| v[0].code.as_ref()), Some("inventory"));
+        // Like async custom functions, they are only run by `ValidateAsync`
+        assert!(listing.validate().is_ok());
+    })
+}
@@ -0,0 +1,104 @@
+use std::collections::{BTreeMap, HashMap};
+use validator::prelude::*;
+use validator::validators::*;
+
+#[derive(validator::Validate)]
+struct Tag {
+    #[validate(MinLength(2))]
+    name: String,
+}
+
+#[derive(validator::Validate)]
+struct Profile {
+    #[validate(each(Email))]
+    emails: Vec<String>,
+    #[validate(each(MaxLength(5)))]
+    codes: [String; 2],
+    #[validate(each(Email))]
+    backup_email: Option<String>,
+    #[validate(nested)]
+    tags: Vec<Tag>,
+    #[validate(each(nested))]
+    labels: BTreeMap<String, Tag>,
+    #[validate(nested)]
+    extra: HashMap<String, Tag>,
+    #[validate(each(each(MinLength(2))))]
+    groups: Vec<Vec<String>>,
+}
+
+fn tag(name: &str) -> Tag {
+    Tag { name: name.to_string() }
+}
+
+fn valid_profile() -> Profile {
+    Profile {
+        emails: vec!["john@example.com".to_string()],
+        codes: ["A1".to_string(), "B2".to_string()],
+        backup_email: None,
+        tags: vec![tag("rust")],
+        labels: BTreeMap::from([("x".to_string(), tag("red"))]),
+        extra: HashMap::new(),
+        groups: vec![vec!["ab".to_string()]],
+    }
+}
+
+#[test]
+fn collections_ok() {
+    let p = valid_profile();
+    assert!(p.validate().is_ok());
+    assert!(p.complete_validate().is_ok());
+}
+
+#[test]
+fn each_short_circuit() {
+    let mut p = valid_profile();
+    p.emails.push("invalid@".to_string());
+    assert_eq!(p.validate().unwrap_err().code, "email");
+}
+
+#[test]
+fn each_keys_by_index() {
+    let mut p = valid_profile();
+    p.emails = vec!["john@example.com".to_string(), "a@b.c".to_string(), "invalid@".to_string()];
+    p.codes[1] = "TOO LONG".to_string();
+    let err = p.complete_validate().unwrap_err();
+    assert_eq!(err.len(), 2);
+    assert_eq!(err.get("emails[2]").map(|v| v[0].code.as_ref()), Some("email"));
+    assert_eq!(err.get("codes[1]").map(|v| v[0].code.as_ref()), Some("max_length"));
+}
+
+#[test]
+fn each_option() {
+    let mut p = valid_profile();
+    p.backup_email = Some("invalid@".to_string());
+    let err = p.complete_validate().unwrap_err();
+    assert_eq!(err.get("backup_email").map(|v| v.len()), Some(1));
+}
+
+#[test]
+fn nested_collections_keys() {
+    let mut p = valid_profile();
+    p.tags.push(tag("x"));
+    p.labels.insert("y".to_string(), tag("b"));
+    p.extra.insert("z".to_string(), tag("c"));
+    p.groups.push(vec!["ok".to_string(), "n".to_string()]);
+    let err = p.complete_validate().unwrap_err();
+    assert_eq!(err.len(), 4);
+    assert!(err.contains_key("tags[1].name"));
+    assert!(err.contains_key("labels[\"y\"].name"));
+    assert!(err.contains_key("extra[\"z\"].name"));
+    assert!(err.contains_key("groups[1][1]"));
+}
+
+#[test]
+fn nested_over_references() {
+    #[derive(validator::Validate)]
+    struct Team<'a> {
+        #[validate(nested)]
+        members: Vec<&'a Tag>,
+    }
+    let bad = tag("x");
+    let team = Team { members: vec![&bad] };
+    let err = team.complete_validate().unwrap_err();
+    assert!(err.contains_key("members[0].name"));
+}
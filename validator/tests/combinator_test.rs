@@ -0,0 +1,101 @@
+use validator::prelude::*;
+use validator::validators::*;
+
+#[test]
+fn and_or() {
+    let v = MinLength::new(3).and(MaxLength::new(5));
+    assert!(v.validate("abcd").is_ok());
+    assert_eq!(v.validate("ab").unwrap_err().code, "min_length");
+    assert_eq!(v.validate("abcdef").unwrap_err().code, "max_length");
+
+    let v = Email::default().or(MaxLength::new(3));
+    assert!(v.validate("jo@example.com").is_ok());
+    assert!(v.validate("jo").is_ok());
+    let err = v.validate("john").unwrap_err();
+    assert_eq!(err.code, "any_of");
+    assert_eq!(err.params["codes"], "email, max_length");
+    assert_eq!(err.message, "Must satisfy one of: Invalid email format; String too long (max 3)");
+}
+
+#[test]
+fn not_optional_map() {
+    let v = Email::default().not().with_message("{field} must not be an email");
+    assert!(v.validate("john").is_ok());
+    let err = v.validate("jo@example.com").unwrap_err();
+    assert_eq!(err.code, "not");
+    assert_eq!(err.with_field("login").message, "login must not be an email");
+
+    let v = Positive::default().optional();
+    assert!(v.validate(&None::<i32>).is_ok());
+    assert!(v.validate(&Some(1)).is_ok());
+    assert_eq!(v.validate(&Some(-1)).unwrap_err().code, "positive");
+
+    let v = MinLength::new(3).map(|s: &String| s.trim().to_string());
+    assert!(v.validate(&"abc".to_string()).is_ok());
+    assert_eq!(v.validate(&" ab ".to_string()).unwrap_err().params["len"], "2");
+}
+
+#[test]
+fn lists() {
+    let v = AllOf::new((MinLength::new(2), MaxLength::new(4), NotAllowedChars::new(["!"])));
+    assert!(v.validate("abc").is_ok());
+    assert_eq!(v.validate("ab!").unwrap_err().code, "not_allowed_chars");
+
+    let dynamic: Vec<Box<dyn Validator<str>>> = vec![Box::new(Email::default()), Box::new(MaxLength::new(2))];
+    let v = AnyOf::new(dynamic).with_message("Expected an email or an initial");
+    assert!(v.validate("jo").is_ok());
+    assert_eq!(v.validate("john").unwrap_err().message, "Expected an email or an initial");
+}
+
+fn check_phone(value: &str) -> Result<(), ValidationError> {
+    if value.starts_with('+') && value[1..].chars().all(|c| c.is_ascii_digit()) {
+        Ok(())
+    } else {
+        Err(ValidationError::new("phone", "Invalid phone number"))
+    }
+}
+
+#[derive(Default)]
+struct Phone;
+
+impl Validator<String> for Phone {
+    fn validate(&self, value: &String) -> Result<(), ValidationError> { check_phone(value) }
+}
+
+#[derive(validator::Validate)]
+struct Contact {
+    #[validate(any(Email, Phone))]
+    reach: String,
+    #[validate(not(Email), all(MinLength(2), MaxLength(8)))]
+    nick: String,
+    #[validate(any(Email, Phone, message = "{field} must be an email or a phone number"))]
+    backup: String,
+    #[validate(each(any(Email, Phone)))]
+    others: Vec<String>,
+}
+
+#[test]
+fn derived_combinators() {
+    let c = Contact {
+        reach: "+123".into(),
+        nick: "jo".into(),
+        backup: "jo@example.com".into(),
+        others: vec!["+1".into()],
+    };
+    assert!(c.validate().is_ok());
+
+    let c = Contact {
+        reach: "jo".into(),
+        nick: "jo@example.com".into(),
+        backup: "x".into(),
+        others: vec!["+1".into(), "y".into()],
+    };
+    let errs = c.complete_validate().unwrap_err();
+    let reach = &errs.get("reach").unwrap()[0];
+    assert_eq!(reach.code, "any_of");
+    assert_eq!(reach.params["codes"], "email, phone");
+    let nick: Vec<_> = errs.get("nick").unwrap().iter().map(|e| e.code.as_ref()).collect();
+    assert_eq!(nick, ["not", "max_length"]);
+    assert_eq!(errs.get("backup").unwrap()[0].message, "backup must be an email or a phone number");
+    assert_eq!(errs.get("others[1]").unwrap()[0].code, "any_of");
+}
@@ -0,0 +1,116 @@
+use validator::prelude::*;
+use validator::validators::*;
+
+struct Tenant {
+    domain: &'static str,
+    reserved: Vec<&'static str>,
+}
+
+/// Requires addresses of the tenant's own domain.
+#[derive(Default)]
+struct TenantDomain;
+
+impl ContextValidator<String, Tenant> for TenantDomain {
+    fn validate_with(&self, value: &String, tenant: &Tenant) -> Result<(), ValidationError> {
+        if value.ends_with(&format!("@{}", tenant.domain)) {
+            Ok(())
+        } else {
+            Err(ValidationError::new("tenant_domain", "{field} must belong to the tenant domain"))
+        }
+    }
+}
+
+fn check_not_reserved(name: &str, tenant: &Tenant) -> Result<(), ValidationError> {
+    if tenant.reserved.contains(&name) { Err(ValidationError::new("reserved", "Name is reserved")) } else { Ok(()) }
+}
+
+fn check_team_size(team: &Team, tenant: &Tenant) -> Result<(), ValidationError> {
+    if team.members.len() > tenant.reserved.len() + 1 {
+        Err(ValidationError::new("team_size", "Team too large"))
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(validator::Validate)]
+struct Profile {
+    #[validate(MinLength(2))]
+    nickname: String,
+}
+
+#[derive(validator::Validate)]
+#[validate(context = "Tenant")]
+struct Member {
+    #[validate(Email, TenantDomain)]
+    email: String,
+    #[validate(custom(function = "check_not_reserved", use_context))]
+    name: String,
+    // context-free structs can be nested into structs with a context
+    #[validate(nested)]
+    profile: Profile,
+}
+
+#[derive(validator::Validate)]
+#[validate(context = Tenant, schema(function = "check_team_size", use_context))]
+struct Team {
+    #[validate(nested)]
+    members: Vec<Member>,
+}
+
+fn tenant() -> Tenant {
+    Tenant { domain: "example.com", reserved: vec!["admin"] }
+}
+
+fn member(email: &str, name: &str) -> Member {
+    Member { email: email.to_string(), name: name.to_string(), profile: Profile { nickname: "jo".to_string() } }
+}
+
+#[test]
+fn context_ok() {
+    let m = member("jo@example.com", "jo");
+    assert!(m.validate_with(&tenant()).is_ok());
+    assert!(m.complete_validate_with(&tenant()).is_ok());
+}
+
+#[test]
+fn context_validator_uses_context() {
+    let m = member("jo@other.com", "jo");
+    let err = m.validate_with(&tenant()).unwrap_err();
+    assert_eq!(err.code, "tenant_domain");
+    assert_eq!(err.message, "email must belong to the tenant domain");
+
+    let other = Tenant { domain: "other.com", reserved: vec![] };
+    assert!(m.validate_with(&other).is_ok());
+}
+
+#[test]
+fn context_threaded_to_nested_and_functions() {
+    let m = Member {
+        email: "invalid".to_string(),
+        name: "admin".to_string(),
+        profile: Profile { nickname: "j".to_string() },
+    };
+    let errs = m.complete_validate_with(&tenant()).unwrap_err();
+    let codes: Vec<_> = errs.get("email").unwrap().iter().map(|e| e.code.as_ref()).collect();
+    assert_eq!(codes, vec!["email", "tenant_domain"]);
+    assert_eq!(errs.get("name").map(|v| v[0].code.as_ref()), Some("reserved"));
+    assert!(errs.contains_key("profile.nickname"));
+}
+
+#[test]
+fn context_threaded_through_collections_and_schema() {
+    let team = Team {
+        members: vec![member("a@example.com", "a"), member("b@other.com", "b"), member("c@example.com", "c")],
+    };
+    let errs = team.complete_validate_with(&tenant()).unwrap_err();
+    assert!(errs.contains_key("members[1].email"));
+    assert_eq!(errs.errors()[0].code, "team_size");
+}
+
+#[test]
+fn context_free_structs_accept_any_context() {
+    let p = Profile { nickname: "j".to_string() };
+    assert!(p.validate_with(&tenant()).is_err());
+    assert!(p.validate_with(&()).is_err());
+    assert!(Profile { nickname: "jo".to_string() }.validate_with(&42).is_ok());
+}
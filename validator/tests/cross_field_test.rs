@@ -0,0 +1,110 @@
+use validator::prelude::*;
+use validator::validators::*;
+
+#[derive(validator::Validate)]
+struct Signup {
+    #[validate(MinLength(8))]
+    password: String,
+    #[validate(MustMatch(other = "password"))]
+    password_confirm: String,
+}
+
+#[derive(validator::Validate)]
+struct PriceFilter {
+    #[validate(LessThanField(other = "max"))]
+    min: u32,
+    #[validate(GreaterThanField(other = "min", or_equal = true))]
+    max: u32,
+}
+
+#[derive(validator::Validate)]
+struct Address {
+    country: String,
+    #[validate(RequiredIf(field = "country", eq = "US"))]
+    state: Option<String>,
+    #[validate(RequiredUnless(field = "country", eq = "US", message = "{field} is required outside the US"))]
+    region: String,
+}
+
+#[derive(validator::Validate)]
+struct Contact {
+    #[validate(MutuallyExclusive(other = "phone"))]
+    email: Option<String>,
+    phone: Option<String>,
+}
+
+#[derive(validator::Validate)]
+struct Span(u32, #[validate(GreaterThanField(other = "0"))] u32);
+
+#[test]
+fn must_match() {
+    let s = Signup { password: "secret123".to_string(), password_confirm: "secret123".to_string() };
+    assert!(s.validate().is_ok());
+
+    let s = Signup { password: "secret123".to_string(), password_confirm: "secret124".to_string() };
+    let err = s.validate().unwrap_err();
+    assert_eq!(err.code, "must_match");
+    assert_eq!(err.message, "Value must match password");
+    assert_eq!(err.params.get("other").map(|s| s.as_ref()), Some("password"));
+    assert_eq!(err.params.get("field").map(|s| s.as_ref()), Some("password_confirm"));
+
+    // the error is attached to the annotated field
+    let errs = s.complete_validate().unwrap_err();
+    assert_eq!(errs.len(), 1);
+    assert!(errs.contains_key("password_confirm"));
+}
+
+#[test]
+fn field_comparison() {
+    assert!(PriceFilter { min: 1, max: 2 }.validate().is_ok());
+
+    let errs = PriceFilter { min: 2, max: 2 }.complete_validate().unwrap_err();
+    assert_eq!(errs.get("min").map(|v| v[0].code.as_ref()), Some("less_than_field"));
+    assert!(!errs.contains_key("max"));
+
+    let errs = PriceFilter { min: 3, max: 2 }.complete_validate().unwrap_err();
+    assert_eq!(errs.get("max").map(|v| v[0].message.as_ref()), Some("Value must be greater than min"));
+}
+
+#[test]
+fn required_if_and_unless() {
+    let a = Address { country: "US".to_string(), state: Some("NY".to_string()), region: String::new() };
+    assert!(a.validate().is_ok());
+
+    let a = Address { country: "US".to_string(), state: None, region: String::new() };
+    let errs = a.complete_validate().unwrap_err();
+    assert_eq!(errs.get("state").map(|v| v[0].code.as_ref()), Some("required"));
+    assert!(!errs.contains_key("region"));
+
+    let a = Address { country: "DE".to_string(), state: None, region: String::new() };
+    let errs = a.complete_validate().unwrap_err();
+    assert!(!errs.contains_key("state"));
+    assert_eq!(errs.get("region").map(|v| v[0].message.as_ref()), Some("region is required outside the US"));
+}
+
+#[test]
+fn mutually_exclusive() {
+    assert!(Contact { email: Some("a@b.c".to_string()), phone: None }.validate().is_ok());
+    assert!(Contact { email: None, phone: None }.validate().is_ok());
+
+    let err = Contact { email: Some("a@b.c".to_string()), phone: Some("123".to_string()) }
+        .validate()
+        .unwrap_err();
+    assert_eq!(err.code, "mutually_exclusive");
+    assert_eq!(err.message, "Value cannot be combined with phone");
+}
+
+#[test]
+fn tuple_struct_sibling() {
+    assert!(Span(1, 2).validate().is_ok());
+    let errs = Span(2, 1).complete_validate().unwrap_err();
+    assert!(errs.contains_key("1"));
+}
+
+#[test]
+fn cross_field_validator_directly() {
+    assert!(MustMatch::default().validate("a", "a").is_ok());
+    assert!(RequiredIf::new(true).validate(&None::<u32>, &true).is_err());
+    assert!(RequiredIf::new(true).validate(&None::<u32>, &false).is_ok());
+    assert!(RequiredUnless::<bool>::default().validate("", &false).is_err());
+}
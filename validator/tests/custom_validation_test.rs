@@ -0,0 +1,72 @@
+use validator::prelude::*;
+use validator::validators::*;
+
+mod rules {
+    use validator::prelude::ValidationError;
+
+    pub fn check_sku(sku: &str) -> Result<(), ValidationError> {
+        if sku.starts_with("SKU-") { Ok(()) } else { Err(ValidationError::new("invalid", "Invalid SKU")) }
+    }
+
+    pub fn check_even(value: &u32) -> Result<(), ValidationError> {
+        if value.is_multiple_of(2) { Ok(()) } else { Err(ValidationError::new("even", "{field} must be even")) }
+    }
+}
+
+#[derive(validator::Validate)]
+struct Product {
+    #[validate(custom(function = "rules::check_sku", code = "sku"))]
+    sku: String,
+    #[validate(MaxLength(20), custom(function = rules::check_sku, message = "{field} needs a SKU prefix"))]
+    parent_sku: String,
+    #[validate(each(custom(function = "rules::check_even")))]
+    pack_sizes: Vec<u32>,
+    max_stock: u32,
+    #[validate(custom(function = |stock: &u32| {
+        if *stock <= self.max_stock { Ok(()) } else { Err(ValidationError::new("stock", "Too much stock")) }
+    }))]
+    stock: u32,
+}
+
+fn product() -> Product {
+    Product {
+        sku: "SKU-1".to_string(),
+        parent_sku: "SKU-0".to_string(),
+        pack_sizes: vec![2, 4],
+        max_stock: 10,
+        stock: 5,
+    }
+}
+
+#[test]
+fn custom_ok() {
+    let p = product();
+    assert!(p.validate().is_ok());
+    assert!(p.complete_validate().is_ok());
+}
+
+#[test]
+fn custom_code_override() {
+    let p = Product { sku: "123".to_string(), ..product() };
+    let err = p.validate().unwrap_err();
+    assert_eq!(err.code, "sku");
+    assert_eq!(err.message, "Invalid SKU");
+    assert_eq!(err.params.get("field").map(|s| s.as_ref()), Some("sku"));
+}
+
+#[test]
+fn custom_message_override() {
+    let p = Product { parent_sku: "123".to_string(), ..product() };
+    let err = p.validate().unwrap_err();
+    assert_eq!(err.code, "invalid");
+    assert_eq!(err.message, "parent_sku needs a SKU prefix");
+}
+
+#[test]
+fn custom_each_and_closure() {
+    let p = Product { pack_sizes: vec![2, 3], stock: 11, ..product() };
+    let errs = p.complete_validate().unwrap_err();
+    assert_eq!(errs.get("pack_sizes[1]").map(|v| v[0].message.as_ref()), Some("pack_sizes must be even"));
+    assert_eq!(errs.get("stock").map(|v| v[0].code.as_ref()), Some("stock"));
+    assert_eq!(errs.len(), 2);
+}
@@ -68,4 +68,78 @@ fn validate_cyclic_bad_members() {
         root.update_members(vec![&*rp]);
     }
     assert!(root.validate().is_err());
-}
\ No newline at end of file
+}
+#[derive(validator::Validate)]
+struct Node<'a> {
+    #[validate(Email)]
+    email: String,
+    #[validate(nested)]
+    links: Vec<&'a Node<'a>>,
+}
+
+fn node<'a>(email: &str) -> Node<'a> {
+    Node { email: email.into(), links: vec![] }
+}
+
+/// Point `from` at `to`, which may be `from` itself or one of its ancestors.
+fn link<'a>(from: &mut Node<'a>, to: *const Node<'a>) {
+    unsafe { from.links.push(&*to) }
+}
+
+#[test]
+fn nested_cycle_is_validated_once() {
+    let mut a = node("a@example.com");
+    let mut b = node("not-an-email");
+    let ap = &a as *const _;
+    link(&mut b, ap);
+    let bp = &b as *const _;
+    link(&mut a, bp);
+
+    assert_eq!(a.validate().unwrap_err().code, "email");
+    let err = a.complete_validate().unwrap_err();
+    assert_eq!(err.to_string(), "links[0].email: Invalid email format");
+}
+
+#[test]
+fn shared_values_are_not_cycles() {
+    let bad = node("not-an-email");
+    let root = Node { email: "root@example.com".into(), links: vec![&bad, &bad] };
+
+    let err = root.complete_validate().unwrap_err();
+    assert!(err.contains_key("links[0].email"));
+    assert!(err.contains_key("links[1].email"));
+}
+
+#[test]
+fn report_cycles() {
+    let mut root = node("root@example.com");
+    let rp = &root as *const _;
+    link(&mut root, rp);
+
+    assert!(root.validate().is_ok());
+    let err = root.validate_in(&mut ValidationState::new().report_cycles(true)).unwrap_err();
+    assert_eq!(err.code, "cycle");
+    let err = root.complete_validate_in(&mut ValidationState::new().report_cycles(true)).unwrap_err();
+    assert_eq!(err.get("links[0]").map(|v| v[0].code.as_ref()), Some("cycle"));
+}
+
+#[derive(validator::Validate)]
+struct Invite {
+    #[validate(Email)]
+    email: String,
+    #[validate(custom(function = "check_referrer"))]
+    referrer: Option<Box<Invite>>,
+}
+
+fn check_referrer(referrer: &Option<Box<Invite>>) -> Result<(), ValidationError> {
+    referrer.as_ref().map_or(Ok(()), |r| r.validate())
+}
+
+#[test]
+fn unrelated_instance_is_validated_from_a_validator() {
+    let invite = Invite {
+        email: "new@example.com".into(),
+        referrer: Some(Box::new(Invite { email: "not-an-email".into(), referrer: None })),
+    };
+    assert_eq!(invite.validate().unwrap_err().code, "email");
+}
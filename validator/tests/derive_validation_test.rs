@@ -99,3 +99,20 @@ fn user_not_allowed_chars() {
     let err = validator::validate::Validate::validate(&u).unwrap_err();
     assert_eq!(err.code, "not_allowed_chars");
 }
+
+#[derive(validator::Validate)]
+struct Product {
+    #[validate(NotAllowedChars(["<"]), NotAllowedChars([">"], message = "No closing brackets"))]
+    title: String,
+    #[validate(Range(min = 1), Range(max = 10, message = "Too many"))]
+    quantity: u32,
+}
+
+#[test]
+fn same_validator_with_other_arguments() {
+    let p = Product { title: "a>".to_string(), quantity: 11 };
+    let errors = validator::validate::Validate::complete_validate(&p).unwrap_err();
+    assert_eq!(errors.get("title").map(|e| e[0].message.as_ref()), Some("No closing brackets"));
+    assert_eq!(errors.get("quantity").map(|e| e[0].message.as_ref()), Some("Too many"));
+    assert!(validator::validate::Validate::validate(&Product { title: "a".to_string(), quantity: 0 }).is_err());
+}
@@ -0,0 +1,103 @@
+#![cfg(feature = "dynamic")]
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use validator::dynamic::{Field, Schema};
+use validator::prelude::*;
+use validator::validators::*;
+
+#[derive(validator::Validate)]
+struct Address {
+    #[validate(MinLength(3))]
+    street: String,
+}
+
+#[derive(validator::Validate)]
+struct Signup {
+    #[validate(Email, MaxLength(20))]
+    email: String,
+    #[validate(Range(min = 18))]
+    age: i64,
+    #[validate(nested)]
+    address: Address,
+    #[validate(MaxLength(2), each(MinLength(2)))]
+    tags: Vec<String>,
+}
+
+fn signup_schema() -> Schema {
+    Schema::new()
+        .field("email", Field::new().required().string(Email::default()).string(MaxLength::new(20)))
+        .field("age", Field::new().integer(Range::default().min(18)))
+        .field("address", Field::new().nested(Schema::new().field("street", Field::new().string(MinLength::new(3)))))
+        .field("tags", Field::new().list(MaxLength::new(2)).each(Field::new().string(MinLength::new(2))))
+}
+
+#[test]
+fn same_errors_as_derive() {
+    let signup = Signup {
+        email: "not-an-email-but-long-enough".into(),
+        age: 16,
+        address: Address { street: "Ma".into() },
+        tags: vec!["a".into(), "bb".into(), "c".into()],
+    };
+    let value = json!({
+        "email": "not-an-email-but-long-enough",
+        "age": 16,
+        "address": { "street": "Ma" },
+        "tags": ["a", "bb", "c"],
+    });
+    let expected = signup.complete_validate().unwrap_err();
+    assert_eq!(signup_schema().complete_validate(&value).unwrap_err(), expected);
+    assert_eq!(signup_schema().validate(&value).unwrap_err().code, "email");
+
+    let valid = json!({ "email": "jo@example.com", "age": 20, "address": { "street": "Main" }, "tags": ["ab"] });
+    assert!(signup_schema().complete_validate(&valid).is_ok());
+}
+
+#[test]
+fn missing_values_and_types() {
+    let errors = signup_schema().complete_validate(&json!({ "age": "old", "address": null, "tags": "x" })).unwrap_err();
+    assert_eq!(errors.get("email").map(|e| e[0].message.as_ref()), Some("Value is required"));
+    let age = &errors.get("age").unwrap()[0];
+    assert_eq!((age.code.as_ref(), age.message.as_ref()), ("type", "Expected a value of type integer"));
+    assert!(!errors.contains_key("address"));
+    assert_eq!(errors.get("tags").unwrap().len(), 1);
+
+    let err = signup_schema().complete_validate(&json!(["not", "an", "object"])).unwrap_err();
+    assert_eq!(err.errors()[0].params["expected"], "object");
+}
+
+#[test]
+fn maps_and_options() {
+    let mut form: HashMap<String, Value> = HashMap::new();
+    form.insert("email".into(), json!("jo"));
+    form.insert("age".into(), json!(17));
+    let errors = signup_schema().complete_validate(&form).unwrap_err();
+    assert_eq!(errors.to_string(), "age: Value must be greater than or equal to 18\nemail: Invalid email format");
+
+    let options = ValidationOptions::new().only_fields(["age"]);
+    let errors = signup_schema().complete_validate_in(&form, &mut ValidationState::with_options(options)).unwrap_err();
+    assert_eq!(errors.len(), 1);
+}
+
+#[test]
+fn integers_beyond_i64_and_whole_floats() {
+    let schema = Schema::new().field("n", Field::new().integer(Range::default().min(0)));
+    assert!(schema.complete_validate(&json!({ "n": 1.0 })).is_ok());
+    assert_eq!(schema.validate(&json!({ "n": -2.0 })).unwrap_err().code, "range");
+    assert_eq!(schema.validate(&json!({ "n": 1.5 })).unwrap_err().code, "type");
+    assert_eq!(schema.validate(&json!({ "n": u64::MAX })).unwrap_err().code, "integer_range");
+    assert_eq!(schema.validate(&json!({ "n": 1e19 })).unwrap_err().code, "integer_range");
+    assert!(schema.complete_validate(&json!({ "n": i64::MAX })).is_ok());
+}
+
+#[test]
+fn values_closing_a_cycle_are_not_entered() {
+    let value = json!({ "age": 10 });
+    let mut state = ValidationState::new().report_cycles(true);
+    assert!(state.enter(&value));
+    for _ in 0..2 {
+        let errors = signup_schema().complete_validate_in(&value, &mut state).unwrap_err();
+        assert_eq!(errors.errors()[0].code, "cycle");
+        assert!(!errors.contains_key("age"));
+    }
+}
@@ -0,0 +1,88 @@
+use validator::prelude::*;
+use validator::validators::*;
+
+#[derive(validator::Validate)]
+struct Address {
+    #[validate(MinLength(3))]
+    street: String,
+}
+
+#[derive(validator::Validate)]
+enum Payment {
+    Card {
+        #[validate(Length(equal = 16))]
+        number: String,
+        #[validate(Range(min = 1, max = 12))]
+        month: u8,
+        holder: String,
+    },
+    Paypal(#[validate(Email)] String),
+    Invoice {
+        #[validate(nested)]
+        address: Address,
+    },
+    Cash,
+}
+
+#[derive(validator::Validate)]
+enum Event {
+    Rename {
+        #[validate(MinLength(1))]
+        old: String,
+        #[validate(MinLength(1), MustMatch(other = "confirm"))]
+        new: String,
+        confirm: String,
+    },
+    Retry(u32, #[validate(GreaterThanField(other = "0"))] u32),
+}
+
+#[derive(validator::Validate)]
+struct Order {
+    #[validate(nested)]
+    payment: Payment,
+}
+
+#[test]
+fn enum_ok() {
+    let card = Payment::Card { number: "1234567812345678".to_string(), month: 5, holder: "Jo".to_string() };
+    assert!(card.validate().is_ok());
+    assert!(Payment::Paypal("jo@example.com".to_string()).validate().is_ok());
+    assert!(Payment::Cash.validate().is_ok());
+    assert!(Payment::Cash.complete_validate().is_ok());
+}
+
+#[test]
+fn enum_validates_active_variant() {
+    let card = Payment::Card { number: "1234".to_string(), month: 13, holder: String::new() };
+    let err = card.validate().unwrap_err();
+    assert_eq!(err.code, "length");
+
+    let errs = card.complete_validate().unwrap_err();
+    assert_eq!(errs.len(), 2);
+    assert!(errs.contains_key("Card.number"));
+    assert!(errs.contains_key("Card.month"));
+
+    let errs = Payment::Paypal("nope".to_string()).complete_validate().unwrap_err();
+    assert_eq!(errs.get("Paypal.0").map(|v| v[0].code.as_ref()), Some("email"));
+
+    let errs = Payment::Invoice { address: Address { street: "Ma".to_string() } }.complete_validate().unwrap_err();
+    assert!(errs.contains_key("Invoice.address.street"));
+}
+
+#[test]
+fn enum_nested_in_struct() {
+    let order = Order { payment: Payment::Paypal("nope".to_string()) };
+    let errs = order.complete_validate().unwrap_err();
+    assert!(errs.contains_key("payment.Paypal.0"));
+}
+
+#[test]
+fn enum_cross_field() {
+    let rename = Event::Rename { old: "a".to_string(), new: "b".to_string(), confirm: "c".to_string() };
+    let errs = rename.complete_validate().unwrap_err();
+    assert_eq!(errs.get("Rename.new").map(|v| v[0].code.as_ref()), Some("must_match"));
+
+    assert!(Event::Retry(1, 2).validate().is_ok());
+    let errs = Event::Retry(2, 1).complete_validate().unwrap_err();
+    assert!(errs.contains_key("Retry.1"));
+}
@@ -0,0 +1,66 @@
+use validator::prelude::*;
+use validator::validators::*;
+
+#[derive(validator::Validate)]
+struct User {
+    #[validate(MaxLength(5))]
+    name: String,
+    #[validate(Email)]
+    email: String,
+}
+
+fn catalog() -> MessageCatalog {
+    let mut catalog = MessageCatalog::new()
+        .with_fallback("en")
+        .with("en", "email", "Invalid email address")
+        .with("de", "email", "Ungültige E-Mail-Adresse");
+    catalog
+        .add_ftl(
+            "fr",
+            "# French messages\n\
+             email = Adresse e-mail invalide\n\
+             \n\
+             max_length = { $field } : { $limit } caractères au maximum ({len})\n",
+        )
+        .unwrap();
+    catalog
+}
+
+#[test]
+fn resolve_by_code_and_params() {
+    let err = MaxLength::new(5).validate(&"Johnny".to_string()).unwrap_err().with_field("name");
+    let catalog = catalog();
+    assert_eq!(catalog.resolve("fr", &err), "name : 5 caractères au maximum (6)");
+    // region falls back to language
+    assert_eq!(catalog.resolve("fr-LU", &err), "name : 5 caractères au maximum (6)");
+    // no template: keep the error's own message
+    assert_eq!(catalog.resolve("de", &err), "String too long (max 5)");
+    assert_eq!(err.message, "String too long (max 5)");
+}
+
+#[test]
+fn fallback_locale() {
+    let err = Email::default().validate(&"invalid@".to_string()).unwrap_err();
+    let catalog = catalog();
+    assert_eq!(catalog.resolve("de_LU", &err), "Ungültige E-Mail-Adresse");
+    assert_eq!(catalog.resolve("lb", &err), "Invalid email address");
+}
+
+#[test]
+fn localize_error_tree() {
+    let u = User { name: "Johnny".to_string(), email: "invalid@".to_string() };
+    let errors = u.complete_validate().unwrap_err();
+    let fr = errors.localize(&catalog(), "fr");
+    assert_eq!(fr.get("email").map(|v| v[0].message.as_ref()), Some("Adresse e-mail invalide"));
+    assert_eq!(fr.get("name").map(|v| v[0].message.as_ref()), Some("name : 5 caractères au maximum (6)"));
+    assert_eq!(fr.get("name").map(|v| v[0].code.as_ref()), Some("max_length"));
+    // the original errors stay locale-neutral
+    assert_eq!(errors.get("email").map(|v| v[0].message.as_ref()), Some("Invalid email format"));
+}
+
+#[test]
+fn malformed_ftl_line() {
+    let mut catalog = MessageCatalog::new();
+    assert_eq!(catalog.add_ftl("fr", "email = ok\nno separator here\n"), Err(2));
+    assert_eq!(catalog.add_ftl("fr", " = missing code"), Err(1));
+}
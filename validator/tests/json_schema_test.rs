@@ -0,0 +1,312 @@
+#![cfg(feature = "json_schema")]
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use validator::json_schema::{JsonSchema, JsonSchemaRule};
+use validator::prelude::*;
+use validator::validators::*;
+
+#[derive(validator::Validate)]
+struct Address {
+    #[validate(MinLength(3, mode = LengthMode::Chars), NotAllowedChars(["$", "."]))]
+    street: String,
+}
+
+#[derive(Default)]
+struct Slug;
+
+impl Validator<String> for Slug {
+    fn validate(&self, value: &String) -> Result<(), ValidationError> {
+        if value.chars().all(|c| c.is_ascii_lowercase() || c == '-') { Ok(()) } else { Err(ValidationError::new("slug", "Invalid slug")) }
+    }
+}
+
+impl JsonSchemaRule for Slug {
+    fn extend_schema(&self, schema: &mut Map<String, Value>) {
+        schema.insert("pattern".into(), json!("^[a-z-]+$"));
+    }
+}
+
+#[derive(Default)]
+struct Untranslated;
+
+impl Validator<String> for Untranslated {
+    fn validate(&self, _: &String) -> Result<(), ValidationError> { Ok(()) }
+}
+
+fn check_nothing(_: &i64) -> Result<(), ValidationError> { Ok(()) }
+
+#[derive(validator::Validate)]
+struct Product {
+    #[validate(Slug, Untranslated, Length(min = 2, max = 20, mode = LengthMode::Chars))]
+    slug: String,
+    #[validate(Range(min = 1, exclusive_max = 100), NonZero)]
+    quantity: u32,
+    #[validate(Negative, custom(function = "check_nothing"))]
+    balance: i64,
+    #[validate(nested)]
+    address: Address,
+    #[validate(MaxLength(3), each(any(Email, Slug)))]
+    contacts: Vec<String>,
+    #[validate(each(not(Email)))]
+    labels: HashMap<String, String>,
+    #[validate(nested)]
+    backup: Option<Address>,
+}
+
+#[test]
+fn struct_schema() {
+    let address = json!({
+        "type": "object",
+        "properties": { "street": { "type": "string", "minLength": 3, "not": { "pattern": "\\$|\\." } } },
+        "required": ["street"],
+    });
+    assert_eq!(Address::json_schema(), address);
+    assert_eq!(
+        Product::json_schema(),
+        json!({
+            "type": "object",
+            "properties": {
+                "slug": {
+                    "type": "string",
+                    "pattern": "^[a-z-]+$",
+                    "minLength": 2,
+                    "maxLength": 20,
+                    "x-validator": [{ "name": "Untranslated" }],
+                },
+                "quantity": { "type": "integer", "minimum": 1, "exclusiveMaximum": 100, "not": { "const": 0 } },
+                "balance": {
+                    "type": "integer",
+                    "exclusiveMaximum": 0,
+                    "x-validator": [{ "name": "custom", "function": "check_nothing" }],
+                },
+                "address": address,
+                "contacts": {
+                    "type": "array",
+                    "maxItems": 3,
+                    "items": { "type": "string", "anyOf": [{ "format": "email" }, { "pattern": "^[a-z-]+$" }] },
+                },
+                "labels": { "type": "object", "additionalProperties": { "type": "string", "not": { "format": "email" } } },
+                "backup": {
+                    "type": ["object", "null"],
+                    "properties": address["properties"],
+                    "required": ["street"],
+                },
+            },
+            "required": ["slug", "quantity", "balance", "address", "contacts", "labels"],
+        })
+    );
+}
+
+/// Generic over the validated type, which is only known from the field.
+struct NotDefault<T>(PhantomData<T>);
+
+impl<T> Default for NotDefault<T> {
+    fn default() -> Self { NotDefault(PhantomData) }
+}
+
+impl<T: Default + PartialEq> Validator<T> for NotDefault<T> {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        if *value == T::default() { Err(ValidationError::new("not_default", "Value must be set")) } else { Ok(()) }
+    }
+}
+
+#[derive(validator::Validate)]
+struct Account {
+    #[validate(NotDefault)]
+    name: String,
+    #[validate(each(NotDefault))]
+    ids: Vec<u32>,
+    #[validate(MustMatch(other = "name"))]
+    confirm_name: String,
+}
+
+#[test]
+fn rules_without_keywords_are_annotated() {
+    let account = Account { name: "jo".into(), ids: vec![0], confirm_name: "jo".into() };
+    assert_eq!(account.complete_validate().unwrap_err().to_string(), "ids[0]: Value must be set");
+    assert_eq!(
+        Account::json_schema(),
+        json!({
+            "type": "object",
+            "properties": {
+                "name": { "type": "string", "x-validator": [{ "name": "NotDefault" }] },
+                "ids": { "type": "array", "items": { "type": "integer", "x-validator": [{ "name": "NotDefault" }] } },
+                "confirm_name": { "type": "string", "x-validator": [{ "name": "MustMatch", "other": "name" }] },
+            },
+            "required": ["name", "ids", "confirm_name"],
+        })
+    );
+}
+
+#[derive(validator::Validate)]
+enum Payment {
+    Card {
+        #[validate(Length(equal = 16))]
+        number: String,
+    },
+    Paypal(#[validate(Email)] String),
+    Cash,
+}
+
+#[derive(validator::Validate)]
+struct Point(#[validate(Positive)] f64, #[validate(Range(max = 10.5))] f64);
+
+#[test]
+fn enum_and_tuple_schema() {
+    assert!(Payment::Card { number: "4111111111111111".into() }.validate().is_ok());
+    assert!(Payment::Paypal("jo".into()).validate().is_err());
+    assert!(Payment::Cash.validate().is_ok());
+    assert_eq!(
+        Payment::json_schema(),
+        json!({
+            "oneOf": [
+                {
+                    "type": "object",
+                    "properties": {
+                        "Card": {
+                            "type": "object",
+                            "properties": {
+                                "number": {
+                                    "type": "string",
+                                    "x-validator": [{ "name": "Length", "equal": 16, "mode": "bytes" }],
+                                },
+                            },
+                            "required": ["number"],
+                        },
+                    },
+                    "required": ["Card"],
+                },
+                {
+                    "type": "object",
+                    "properties": { "Paypal": { "type": "string", "format": "email" } },
+                    "required": ["Paypal"],
+                },
+                { "const": "Cash" },
+            ]
+        })
+    );
+    assert_eq!(
+        Point::json_schema(),
+        json!({
+            "type": "array",
+            "prefixItems": [{ "type": "number", "exclusiveMinimum": 0 }, { "type": "number", "maximum": 10.5 }],
+        })
+    );
+}
+
+#[derive(validator::Validate)]
+struct Profile {
+    #[validate(each(MinLength(2)))]
+    nickname: Option<String>,
+    #[validate(each(MaxLength(3)))]
+    tags: Option<Vec<String>>,
+}
+
+#[test]
+fn option_schemas_accept_null() {
+    assert_eq!(Option::<u8>::json_schema(), json!({ "type": ["integer", "null"] }));
+    assert_eq!(Option::<Option<u8>>::json_schema(), json!({ "type": ["integer", "null"] }));
+    assert_eq!(Option::<Payment>::json_schema()["anyOf"][1], json!({ "type": "null" }));
+    assert_eq!(
+        Profile::json_schema(),
+        json!({
+            "type": "object",
+            "properties": {
+                "nickname": { "type": ["string", "null"], "x-validator": [{ "name": "MinLength", "limit": 2, "mode": "bytes" }] },
+                "tags": { "type": ["array", "null"], "items": { "type": "string" }, "maxItems": 3 },
+            },
+        })
+    );
+}
+
+#[derive(validator::Validate)]
+struct Username {
+    #[validate(MinLength(2, mode = LengthMode::Chars), MaxLength(20), MaxLength(16, mode = LengthMode::Utf16))]
+    name: String,
+    #[validate(Length(min = 1, max = 3, mode = LengthMode::Graphemes))]
+    aliases: Vec<String>,
+}
+
+#[test]
+fn string_lengths_outside_chars_mode_are_annotated() {
+    assert_eq!(
+        Username::json_schema(),
+        json!({
+            "type": "object",
+            "properties": {
+                "name": {
+                    "type": "string",
+                    "minLength": 2,
+                    "x-validator": [
+                        { "name": "MaxLength", "limit": 20, "mode": "bytes" },
+                        { "name": "MaxLength", "limit": 16, "mode": "utf16" },
+                    ],
+                },
+                "aliases": { "type": "array", "items": { "type": "string" }, "minItems": 1, "maxItems": 3 },
+            },
+            "required": ["name", "aliases"],
+        })
+    );
+}
+
+#[derive(validator::Validate)]
+struct Chain {
+    #[validate(MinLength(1, mode = LengthMode::Chars))]
+    name: String,
+    #[validate(nested)]
+    next: Option<Box<Chain>>,
+}
+
+#[derive(validator::Validate)]
+struct Category {
+    #[validate(nested)]
+    children: Vec<Category>,
+}
+
+#[derive(validator::Validate)]
+struct Catalog {
+    #[validate(nested)]
+    root: Category,
+    #[validate(nested)]
+    archive: Category,
+    #[validate(nested)]
+    address: Address,
+}
+
+#[test]
+fn recursive_types_are_referenced() {
+    let chain = Chain { name: "a".into(), next: Some(Box::new(Chain { name: String::new(), next: None })) };
+    assert_eq!(chain.complete_validate().unwrap_err().to_string(), "next.name: String too short (min 1)");
+    assert_eq!(
+        Chain::json_schema(),
+        json!({
+            "$ref": "#/$defs/Chain",
+            "$defs": {
+                "Chain": {
+                    "type": "object",
+                    "properties": {
+                        "name": { "type": "string", "minLength": 1 },
+                        "next": { "anyOf": [{ "$ref": "#/$defs/Chain" }, { "type": "null" }] },
+                    },
+                    "required": ["name"],
+                },
+            },
+        })
+    );
+    let catalog = Catalog::json_schema();
+    assert_eq!(catalog["properties"]["root"], json!({ "$ref": "#/$defs/Category" }));
+    assert_eq!(catalog["properties"]["archive"], json!({ "$ref": "#/$defs/Category" }));
+    assert_eq!(catalog["properties"]["address"]["type"], "object");
+    assert_eq!(
+        catalog["$defs"],
+        json!({
+            "Category": {
+                "type": "object",
+                "properties": { "children": { "type": "array", "items": { "$ref": "#/$defs/Category" } } },
+                "required": ["children"],
+            },
+        })
+    );
+    assert_eq!(Address::json_schema().get("$defs"), None);
+}
@@ -0,0 +1,57 @@
+use validator::prelude::*;
+use validator::validators::*;
+
+#[derive(validator::Validate)]
+struct Person {
+    #[validate(MaxLength(6, mode = LengthMode::Chars))]
+    last_name: String,
+    #[validate(MinLength(2, mode = LengthMode::Graphemes), MaxLength(2, mode = LengthMode::Graphemes))]
+    initials: String,
+    #[validate(MaxLength(4, mode = LengthMode::Utf16))]
+    nickname: String,
+}
+
+#[test]
+fn count_modes() {
+    // "ü" written as `u` + combining diaeresis, and a thumbs up with skin tone
+    let value = "Mu\u{308}ller 👍🏽";
+    assert_eq!(LengthMode::Bytes.count(value), 17);
+    assert_eq!(LengthMode::Chars.count(value), 10);
+    assert_eq!(LengthMode::Graphemes.count(value), 8);
+    assert_eq!(LengthMode::Utf16.count(value), 12);
+}
+
+#[test]
+fn bytes_is_the_default_mode() {
+    let err = MaxLength::new(6).validate(&"Müller".to_string()).unwrap_err();
+    assert_eq!(err.params.get("len").map(|s| s.as_ref()), Some("7"));
+    assert_eq!(err.params.get("mode").map(|s| s.as_ref()), Some("bytes"));
+}
+
+#[test]
+fn selected_mode_is_reported() {
+    let v = MinLength::new(3).mode(LengthMode::Graphemes);
+    let err = v.validate(&"👍🏽👍🏽".to_string()).unwrap_err();
+    assert_eq!(err.params.get("len").map(|s| s.as_ref()), Some("2"));
+    assert_eq!(err.params.get("mode").map(|s| s.as_ref()), Some("graphemes"));
+}
+
+#[test]
+fn derive_with_modes() {
+    let p = Person {
+        last_name: "Müller".to_string(),
+        initials: "👍🏽Ö".to_string(),
+        nickname: "😀😀".to_string(),
+    };
+    assert!(p.validate().is_ok());
+
+    let p = Person {
+        last_name: "Müllerö".to_string(),
+        initials: "Ö".to_string(),
+        nickname: "😀😀a".to_string(),
+    };
+    let errors = p.complete_validate().unwrap_err();
+    assert_eq!(errors.get("last_name").map(|v| v[0].params["len"].as_ref()), Some("7"));
+    assert_eq!(errors.get("initials").map(|v| v[0].code.as_ref()), Some("min_length"));
+    assert_eq!(errors.get("nickname").map(|v| v[0].params["mode"].as_ref()), Some("utf16"));
+}
@@ -0,0 +1,88 @@
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use validator::prelude::*;
+use validator::validators::*;
+
+#[derive(validator::Validate)]
+struct Article<'a> {
+    #[validate(MinLength(3), NotAllowedChars(["<"]))]
+    title: &'a str,
+    #[validate(MaxLength(5))]
+    slug: Cow<'a, str>,
+    #[validate(Email)]
+    author: Box<str>,
+    #[validate(Length(min = 1, max = 3))]
+    tags: Vec<String>,
+    #[validate(Length(equal = 2))]
+    languages: HashSet<String>,
+    #[validate(Length(max = 1))]
+    meta: HashMap<String, String>,
+}
+
+fn valid_article() -> Article<'static> {
+    Article {
+        title: "Hello",
+        slug: Cow::Borrowed("hello"),
+        author: "jane@example.com".into(),
+        tags: vec!["rust".to_string()],
+        languages: HashSet::from(["en".to_string(), "fr".to_string()]),
+        meta: HashMap::new(),
+    }
+}
+
+#[test]
+fn string_like_values() {
+    assert!(MaxLength::new(3).validate("abc").is_ok());
+    assert!(MinLength::new(3).validate(&"ab").is_err());
+    assert!(Email::default().validate("john@example.com").is_ok());
+    assert!(NotAllowedChars::new(["x"]).validate(&Cow::Borrowed("xyz")).is_err());
+    let boxed: Box<str> = "abcdef".into();
+    assert_eq!(MaxLength::new(5).validate(&boxed).unwrap_err().params["len"], "6");
+}
+
+#[test]
+fn collections_count_items() {
+    let v = MaxLength::new(2);
+    assert!(v.validate(&vec![1, 2]).is_ok());
+    assert!(v.validate(&[1, 2, 3]).is_err());
+    assert!(v.validate(&vec!["ab".to_string(); 3][..]).is_err());
+    assert!(MinLength::new(1).validate(&HashSet::<u8>::new()).is_err());
+}
+
+#[test]
+fn combined_length() {
+    let v = Length::new(2, 4);
+    assert!(v.validate("abc").is_ok());
+    let err = v.validate("a").unwrap_err();
+    assert_eq!(err.code, "length");
+    assert_eq!(err.message, "Length must be between 2 and 4");
+    assert_eq!(err.params.get("min").map(|s| s.as_ref()), Some("2"));
+    assert_eq!(err.params.get("max").map(|s| s.as_ref()), Some("4"));
+    assert_eq!(err.params.get("len").map(|s| s.as_ref()), Some("1"));
+
+    let err = Length::default().equal(2).mode(LengthMode::Chars).validate("äöü").unwrap_err();
+    assert_eq!(err.message, "Length must be exactly 2");
+    assert_eq!(err.params.get("mode").map(|s| s.as_ref()), Some("chars"));
+    assert_eq!(Length::default().min(2).validate("a").unwrap_err().message, "Length must be at least 2");
+    assert_eq!(Length::default().max(0).validate("a").unwrap_err().message, "Length must be at most 0");
+    assert!(Length::default().validate("anything").is_ok());
+}
+
+#[test]
+fn derive_on_borrowed_and_collection_fields() {
+    assert!(valid_article().validate().is_ok());
+
+    let mut a = valid_article();
+    a.title = "<b";
+    a.slug = Cow::Owned("too-long".to_string());
+    a.author = "nobody".into();
+    a.tags = vec![];
+    a.languages.clear();
+    a.meta.insert("a".to_string(), "b".to_string());
+    a.meta.insert("c".to_string(), "d".to_string());
+    let errors = a.complete_validate().unwrap_err();
+    assert_eq!(errors.len(), 6);
+    assert_eq!(errors.get("title").map(|v| v.len()), Some(2));
+    assert_eq!(errors.get("tags").map(|v| v[0].message.as_ref()), Some("Length must be between 1 and 3"));
+    assert_eq!(errors.get("languages").map(|v| v[0].params["equal"].as_ref()), Some("2"));
+}
@@ -1,5 +1,5 @@
 use validator::prelude::*;
-use validator::validators::Email;
+use validator::validators::{Email, EmailMode};
 
 #[test]
 fn email_validator_valid_and_invalid_samples() {
@@ -61,4 +61,96 @@ fn email_validator_more_samples() {
     assert_eq!(e.code, "email");
     let e = email.validate(&"trailingwhitespace@example.com ".to_string()).unwrap_err();
     assert_eq!(e.code, "email");
-}
\ No newline at end of file
+}
+fn reason(email: &Email, value: &str) -> Option<String> {
+    email.validate(value).err().map(|e| e.params["reason"].to_string())
+}
+
+#[test]
+fn email_validator_rejects_malformed_domains_and_lengths() {
+    let email = Email::default();
+    assert_eq!(reason(&email, "a@b..c"), Some("format".to_string()));
+    assert_eq!(reason(&email, "a@.example.com"), Some("format".to_string()));
+    assert_eq!(reason(&email, "a@example."), Some("format".to_string()));
+    let label = "a".repeat(64);
+    assert_eq!(reason(&email, &format!("a@{}.com", label)), Some("format".to_string()));
+    let local = "a".repeat(65);
+    assert_eq!(reason(&email, &format!("{}@example.com", local)), Some("length".to_string()));
+    let domain = format!("{}.com", vec!["a".repeat(60); 5].join("."));
+    assert_eq!(reason(&email, &format!("a@{}", domain)), Some("length".to_string()));
+}
+
+#[test]
+fn email_validator_intranet_addresses() {
+    assert!(Email::default().validate("user@localhost").is_ok());
+    assert!(Email::new(EmailMode::Html5).validate("user@localhost").is_ok());
+    assert!(Email::new(EmailMode::Rfc5322).validate("user@localhost").is_err());
+    assert!(Email::new(EmailMode::Rfc5322).require_tld(false).validate("user@localhost").is_ok());
+    assert!(Email::new(EmailMode::Html5).require_tld(true).validate("user@localhost").is_err());
+}
+
+#[test]
+fn email_validator_html5_mode() {
+    let email = Email::new(EmailMode::Html5);
+    assert!(email.validate("john.doe+tag@example.com").is_ok());
+    assert!(email.validate("!#$%&'*+/=?^_`{|}~-@example.com").is_ok());
+    assert!(email.validate("\"quoted\"@example.com").is_err());
+    assert!(email.validate("john@strange_example.com").is_err());
+    assert!(email.validate("john@-example.com").is_err());
+    assert!(email.validate("jöhn@example.com").is_err());
+}
+
+#[test]
+fn email_validator_rfc5322_mode() {
+    let email = Email::new(EmailMode::Rfc5322);
+    assert!(email.validate("john.doe@example.com").is_ok());
+    assert!(email.validate("\"john doe\"@example.com").is_ok());
+    assert!(email.validate("\"john\\\"doe\"@example.com").is_ok());
+    assert!(email.validate("john..doe@example.com").is_err());
+    assert!(email.validate(".john@example.com").is_err());
+    assert!(email.validate("\"unterminated@example.com").is_err());
+    assert!(email.validate("\"a\"b\"@example.com").is_err());
+    assert!(email.validate("john@example_domain.com").is_err());
+}
+
+#[test]
+fn email_validator_idn_domains() {
+    let email = Email::new(EmailMode::Rfc5322);
+    assert!(email.validate("info@bücher.example").is_ok());
+    assert!(email.validate("info@xn--bcher-kva.example").is_ok());
+    let email = Email::new(EmailMode::Rfc5322).allow_idn(false);
+    assert!(email.validate("info@bücher.example").is_err());
+}
+
+#[test]
+fn email_validator_ip_literals() {
+    let email = Email::default();
+    assert!(email.validate("root@[192.168.0.1]").is_err());
+    let email = Email::default().allow_ip_literal(true);
+    assert!(email.validate("root@[192.168.0.1]").is_ok());
+    assert!(email.validate("root@[IPv6:2001:db8::1]").is_ok());
+    assert!(email.validate("root@[300.1.1.1]").is_err());
+    assert!(email.validate("root@[192.168.0.1").is_err());
+}
+
+#[test]
+fn email_validator_domain_lists() {
+    let email = Email::default().allowed_domains(["example.com"]);
+    assert!(email.validate("a@example.com").is_ok());
+    assert!(email.validate("a@mail.EXAMPLE.com").is_ok());
+    assert_eq!(reason(&email, "a@badexample.com"), Some("domain_not_allowed".to_string()));
+
+    let email = Email::default().denied_domains(["mailinator.com"]);
+    assert!(email.validate("a@example.com").is_ok());
+    let err = email.validate("a@eu.mailinator.com").unwrap_err();
+    assert_eq!(err.code, "email");
+    assert_eq!(err.params["reason"], "domain_denied");
+    assert_eq!(err.message, "Email domain is not allowed");
+
+    let email = Email::default().allowed_domains(["bücher.de"]);
+    assert!(email.validate("a@bücher.de").is_ok());
+    assert!(email.validate("a@shop.xn--bcher-kva.de").is_ok());
+    assert_eq!(reason(&email, "a@buecher.de"), Some("domain_not_allowed".to_string()));
+    let email = Email::default().denied_domains(["BÜCHER.de"]);
+    assert_eq!(reason(&email, "a@bücher.de"), Some("domain_denied".to_string()));
+}
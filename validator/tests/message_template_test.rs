@@ -0,0 +1,54 @@
+use std::collections::BTreeMap;
+use validator::prelude::*;
+use validator::validators::*;
+
+#[derive(validator::Validate)]
+struct User {
+    #[validate(MaxLength(5, message = "{field} must be at most {limit} characters (got {len})"))]
+    name: String,
+    #[validate(NotAllowedChars(["#"], message = "{field} must not contain '{hit}'"))]
+    nick: String,
+    #[validate(Email(message = "{field} is not an email"))]
+    email: String,
+}
+
+#[test]
+fn validator_renders_params() {
+    let v = MaxLength::new(4).with_message("At most {limit} characters (got {len})");
+    let err = v.validate(&"Müller".to_string()).unwrap_err();
+    assert_eq!(err.message, "At most 4 characters (got 7)");
+
+    let v = Positive::default().with_message("{value} is not positive");
+    assert_eq!(v.validate(&-3).unwrap_err().message, "-3 is not positive");
+}
+
+#[test]
+fn derive_renders_field_name() {
+    let u = User { name: "Johnny".to_string(), nick: "#1".to_string(), email: "invalid@".to_string() };
+    let err = u.validate().unwrap_err();
+    assert_eq!(err.message, "name must be at most 5 characters (got 6)");
+    assert_eq!(err.params.get("field").map(|s| s.as_ref()), Some("name"));
+
+    let errors = u.complete_validate().unwrap_err();
+    assert_eq!(errors.get("nick").map(|v| v[0].message.as_ref()), Some("nick must not contain '#'"));
+    assert_eq!(errors.get("email").map(|v| v[0].message.as_ref()), Some("email is not an email"));
+}
+
+#[test]
+fn render_message_keeps_unknown_placeholders() {
+    let mut params = BTreeMap::new();
+    params.insert("limit".into(), "3".into());
+    assert_eq!(render_message("{limit}-{unknown}-{", &params), "3-{unknown}-{");
+    assert_eq!(render_message("no placeholders", &params), "no placeholders");
+
+    let err = ValidationError::new("custom", "{field}: {limit}").with_param("limit", "3").render();
+    assert_eq!(err.message, "{field}: 3");
+    assert_eq!(err.with_field("sku").message, "sku: 3");
+}
+
+#[test]
+fn messages_render_once() {
+    let err = ValidationError::new("custom", "{field} got {value}").with_param("value", "{len}").with_param("len", "3").render();
+    assert_eq!(err.message, "{field} got {len}");
+    assert_eq!(err.with_field("name").message, "name got {len}");
+}
@@ -0,0 +1,78 @@
+use validator::prelude::*;
+use validator::validators::*;
+
+#[derive(validator::Validate)]
+struct Address {
+    #[validate(MinLength(3))]
+    street: String,
+    #[validate(MaxLength(5))]
+    zip: String,
+}
+
+#[derive(validator::Validate)]
+struct User {
+    #[validate(Email)]
+    email: String,
+    #[validate(nested)]
+    address: Address,
+}
+
+#[derive(validator::Validate)]
+struct Order {
+    #[validate(Positive)]
+    quantity: i32,
+    #[validate(nested)]
+    customer: User,
+}
+
+fn address(street: &str, zip: &str) -> Address {
+    Address { street: street.to_string(), zip: zip.to_string() }
+}
+
+#[test]
+fn nested_ok() {
+    let u = User {
+        email: "john@example.com".to_string(),
+        address: address("Main Street", "1234"),
+    };
+    assert!(u.validate().is_ok());
+    assert!(u.complete_validate().is_ok());
+}
+
+#[test]
+fn nested_short_circuit() {
+    let u = User {
+        email: "john@example.com".to_string(),
+        address: address("Ma", "1234"),
+    };
+    let err = u.validate().unwrap_err();
+    assert_eq!(err.code, "min_length");
+}
+
+#[test]
+fn nested_complete_uses_dotted_keys() {
+    let u = User {
+        email: "invalid@".to_string(),
+        address: address("Ma", "123456"),
+    };
+    let err = u.complete_validate().unwrap_err();
+    assert_eq!(err.len(), 3);
+    assert_eq!(err.get("email").map(|v| v[0].code.as_ref()), Some("email"));
+    assert_eq!(err.get("address.street").map(|v| v[0].code.as_ref()), Some("min_length"));
+    assert_eq!(err.get("address.zip").map(|v| v[0].code.as_ref()), Some("max_length"));
+}
+
+#[test]
+fn nested_multiple_levels() {
+    let o = Order {
+        quantity: 0,
+        customer: User {
+            email: "john@example.com".to_string(),
+            address: address("Ma", "1234"),
+        },
+    };
+    let err = o.complete_validate().unwrap_err();
+    assert_eq!(err.len(), 2);
+    assert!(err.contains_key("quantity"));
+    assert!(err.contains_key("customer.address.street"));
+}
@@ -0,0 +1,119 @@
+use validator::prelude::*;
+use validator::validators::*;
+
+#[derive(validator::Validate)]
+struct Address {
+    #[validate(MinLength(3))]
+    street: String,
+}
+
+#[derive(validator::Validate)]
+struct Profile {
+    #[validate(MinLength(5), Email)]
+    email: String,
+    #[validate(MinLength(3))]
+    name: String,
+    #[validate(Positive)]
+    age: i32,
+    #[validate(nested)]
+    address: Address,
+    #[validate(each(MinLength(2)))]
+    tags: Vec<String>,
+}
+
+fn invalid_profile() -> Profile {
+    Profile {
+        email: "ab".into(),
+        name: "x".into(),
+        age: -1,
+        address: Address { street: "y".into() },
+        tags: vec!["a".into(), "b".into(), "c".into()],
+    }
+}
+
+fn complete_with(profile: &Profile, options: ValidationOptions) -> Result<(), ValidationErrors> {
+    profile.complete_validate_in(&mut ValidationState::with_options(options))
+}
+
+fn count(errors: &ValidationErrors) -> usize {
+    errors.iter().map(|(_, errors)| errors.len()).sum()
+}
+
+#[test]
+fn default_options_collect_everything() {
+    let err = complete_with(&invalid_profile(), ValidationOptions::new()).unwrap_err();
+    assert_eq!(count(&err), 8);
+    assert_eq!(err, invalid_profile().complete_validate().unwrap_err());
+}
+
+#[test]
+fn max_errors() {
+    let err = complete_with(&invalid_profile(), ValidationOptions::new().max_errors(3)).unwrap_err();
+    assert_eq!(count(&err), 3);
+    assert_eq!(err.get("email").map(|v| v.len()), Some(2));
+    assert!(err.contains_key("name"));
+
+    let err = complete_with(&invalid_profile(), ValidationOptions::new().max_errors(6)).unwrap_err();
+    assert_eq!(count(&err), 6);
+    assert!(err.contains_key("address.street"));
+    assert!(err.contains_key("tags[0]"));
+    assert!(!err.contains_key("tags[1]"));
+}
+
+#[test]
+fn stop_on_first_error_per_field() {
+    let options = ValidationOptions::new().stop_on_first_error_per_field(true);
+    let err = complete_with(&invalid_profile(), options).unwrap_err();
+    let email: Vec<_> = err.get("email").unwrap().iter().map(|e| e.code.as_ref()).collect();
+    assert_eq!(email, vec!["min_length"]);
+    // items of a collection are values of their own
+    assert_eq!(count(&err), 7);
+}
+
+#[test]
+fn only_fields() {
+    let profile = invalid_profile();
+    let options = ValidationOptions::new().only_fields(["name", "address"]);
+    let err = complete_with(&profile, options.clone()).unwrap_err();
+    assert_eq!(err.to_string(), "address.street: String too short (min 3)\nname: String too short (min 3)");
+
+    let err = profile.validate_in(&mut ValidationState::with_options(options)).unwrap_err();
+    assert_eq!(err.params.get("field").map(|f| f.as_ref()), Some("name"));
+}
+
+#[test]
+fn skip_fields() {
+    let mut profile = invalid_profile();
+    profile.email = "user@example.com".into();
+    profile.age = 30;
+    profile.tags.clear();
+    let options = ValidationOptions::new().skip_fields(["name", "address"]);
+    assert!(complete_with(&profile, options.clone()).is_ok());
+    assert!(profile.validate_in(&mut ValidationState::with_options(options)).is_ok());
+    assert!(profile.validate().is_err());
+}
+
+fn reject_marker(_: &()) -> Result<(), ValidationError> { Err(ValidationError::new("marker", "Marker is set")) }
+
+#[derive(validator::Validate)]
+struct Marker {
+    #[validate(custom(function = "reject_marker"))]
+    tag: (),
+}
+
+#[derive(validator::Validate)]
+struct Tagged {
+    #[validate(MinLength(3))]
+    name: String,
+    #[validate(nested)]
+    marker: Marker,
+}
+
+#[test]
+fn field_filters_skip_nested_zero_sized_values() {
+    let tagged = Tagged { name: "x".into(), marker: Marker { tag: () } };
+    let err = tagged.complete_validate_in(&mut ValidationState::with_options(ValidationOptions::new().only_fields(["marker"]))).unwrap_err();
+    assert_eq!(err.to_string(), "marker.tag: Marker is set");
+    let err = tagged.complete_validate_in(&mut ValidationState::with_options(ValidationOptions::new().skip_fields(["name", "tag"]))).unwrap_err();
+    assert_eq!(err.to_string(), "marker.tag: Marker is set");
+}
@@ -0,0 +1,79 @@
+use validator::prelude::*;
+use validator::validators::*;
+
+#[derive(validator::Validate)]
+struct Product {
+    #[validate(Range(min = 1, max = 100))]
+    quantity: u64,
+    #[validate(Range(exclusive_min = 0.0, message = "{field} must be greater than {min}"))]
+    price: f64,
+    #[validate(Range(-10, 10))]
+    offset: i8,
+    #[validate(Positive, NonZero)]
+    id: u32,
+    #[validate(Negative)]
+    delta: f32,
+}
+
+fn valid_product() -> Product {
+    Product { quantity: 5, price: 9.99, offset: -3, id: 7, delta: -0.5 }
+}
+
+#[test]
+fn range_inclusive_bounds() {
+    let v = Range::new(1, 100);
+    assert!(v.validate(&1).is_ok());
+    assert!(v.validate(&100).is_ok());
+    let err = v.validate(&101).unwrap_err();
+    assert_eq!(err.code, "range");
+    assert_eq!(err.message, "Value must be greater than or equal to 1 and less than or equal to 100");
+    assert_eq!(err.params.get("min").map(|s| s.as_ref()), Some("1"));
+    assert_eq!(err.params.get("max").map(|s| s.as_ref()), Some("100"));
+    assert_eq!(err.params.get("value").map(|s| s.as_ref()), Some("101"));
+}
+
+#[test]
+fn range_exclusive_bounds() {
+    let v = Range::default().exclusive_min(0.0).exclusive_max(1.0);
+    assert!(v.validate(&0.5).is_ok());
+    let err = v.validate(&0.0).unwrap_err();
+    assert_eq!(err.message, "Value must be greater than 0 and less than 1");
+    assert_eq!(err.params.get("exclusive_min").map(|s| s.as_ref()), Some("true"));
+    assert!(v.validate(&1.0).is_err());
+}
+
+#[test]
+fn nan_is_rejected() {
+    let err = Range::default().min(0.0).validate(&f64::NAN).unwrap_err();
+    assert_eq!(err.message, "Value must be a number");
+    assert!(Range::<f64>::default().validate(&f64::NAN).is_err());
+    assert!(Positive::default().validate(&f32::NAN).is_err());
+    assert!(Negative::default().validate(&f64::NAN).is_err());
+    assert!(NonZero::default().validate(&f64::NAN).is_err());
+}
+
+#[test]
+fn sign_validators_generic() {
+    assert!(Positive::default().validate(&1u64).is_ok());
+    assert!(Positive::default().validate(&0.0f64).is_err());
+    assert!(Negative::default().validate(&-1i64).is_ok());
+    assert_eq!(Negative::default().validate(&0u8).unwrap_err().code, "negative");
+    assert!(NonZero::default().validate(&-0.1f32).is_ok());
+    let err = NonZero::default().validate(&0usize).unwrap_err();
+    assert_eq!(err.code, "non_zero");
+    assert_eq!(err.params.get("value").map(|s| s.as_ref()), Some("0"));
+}
+
+#[test]
+fn derive_ranges() {
+    assert!(valid_product().validate().is_ok());
+
+    let p = Product { quantity: 0, price: 0.0, offset: 11, id: 0, delta: 1.0 };
+    let errors = p.complete_validate().unwrap_err();
+    assert_eq!(errors.len(), 5);
+    assert_eq!(errors.get("quantity").map(|v| v[0].code.as_ref()), Some("range"));
+    assert_eq!(errors.get("price").map(|v| v[0].message.as_ref()), Some("price must be greater than 0"));
+    assert_eq!(errors.get("offset").map(|v| v[0].params["max"].as_ref()), Some("10"));
+    assert_eq!(errors.get("id").map(|v| v.len()), Some(2));
+    assert_eq!(errors.get("delta").map(|v| v[0].code.as_ref()), Some("negative"));
+}
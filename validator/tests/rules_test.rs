@@ -0,0 +1,152 @@
+use validator::prelude::*;
+use validator::validators::*;
+
+#[derive(Default)]
+struct Slug;
+
+impl Validator<String> for Slug {
+    fn validate(&self, value: &String) -> Result<(), ValidationError> {
+        if value.chars().all(|c| c.is_ascii_lowercase() || c == '-') {
+            Ok(())
+        } else {
+            Err(ValidationError::new("slug", "Invalid slug"))
+        }
+    }
+}
+
+#[derive(Default)]
+struct Sku;
+
+impl Validator<String> for Sku {
+    fn validate(&self, value: &String) -> Result<(), ValidationError> {
+        if value.starts_with("SKU-") { Ok(()) } else { Err(ValidationError::new("sku", "Invalid SKU")) }
+    }
+}
+
+impl Describe for Sku {
+    fn describe(&self) -> RuleDescriptor { RuleDescriptor::new("Sku", "sku").with_param("prefix", "SKU-") }
+}
+
+fn check_code(code: &str) -> Result<(), ValidationError> {
+    if code.len() == 6 { Ok(()) } else { Err(ValidationError::new("code", "Invalid code")) }
+}
+
+fn check_product(_: &Product) -> Result<(), ValidationError> { Ok(()) }
+
+#[derive(validator::Validate)]
+struct Address {
+    #[validate(MinLength(3))]
+    street: String,
+}
+
+#[derive(validator::Validate)]
+#[validate(schema(function = "check_product"))]
+struct Product {
+    #[validate(Slug, MaxLength(20, message = "{field} is too long"), NotAllowedChars(["$", "."]))]
+    slug: String,
+    #[validate(Sku, custom(function = "check_code", code = "code", message = "Bad code"))]
+    sku: String,
+    #[validate(Range(min = 1, exclusive_max = 100))]
+    quantity: u32,
+    #[validate(GreaterThanField(other = "quantity", or_equal = true))]
+    max_quantity: u32,
+    #[validate(nested)]
+    address: Address,
+    #[validate(Length(equal = 2), each(any(Email, Sku)))]
+    contacts: Vec<String>,
+}
+
+#[test]
+fn field_rules() {
+    let rules = Product::validation_rules();
+    let fields: Vec<_> = rules.iter().map(|f| f.field.as_ref()).collect();
+    assert_eq!(fields, ["slug", "sku", "quantity", "max_quantity", "address", "contacts", "__all__"]);
+
+    let slug = &rules[0].rules;
+    assert_eq!(slug[0], RuleDescriptor::new("Slug", ""));
+    assert_eq!(
+        slug[1],
+        RuleDescriptor::new("MaxLength", "max_length")
+            .with_param("limit", "20")
+            .with_param("mode", "bytes")
+            .with_message(Some("{field} is too long"))
+    );
+    assert_eq!(slug[2].params["forbidden"], "$, .");
+
+    let sku = &rules[1].rules;
+    assert_eq!(sku[0], RuleDescriptor::new("Sku", "sku").with_param("prefix", "SKU-"));
+    assert_eq!((sku[1].name.as_ref(), sku[1].code.as_ref()), ("custom", "code"));
+    assert_eq!(sku[1].params["function"], "check_code");
+    assert_eq!(sku[1].message.as_deref(), Some("Bad code"));
+
+    let quantity = &rules[2].rules[0];
+    assert_eq!(quantity.params["min"], "1");
+    assert_eq!(quantity.params["exclusive_max"], "100");
+
+    let max_quantity = &rules[3].rules[0];
+    assert_eq!(max_quantity.code, "greater_than_field");
+    assert_eq!(max_quantity.params["other"], "quantity");
+    assert_eq!(max_quantity.params["or_equal"], "true");
+
+    assert_eq!(rules[4].rules[0].name, "nested");
+    assert_eq!(Address::validation_rules()[0].rules[0].params["limit"], "3");
+
+    let contacts = &rules[5].rules;
+    assert_eq!(contacts[0].params["equal"], "2");
+    assert_eq!(contacts[1].name, "each");
+    let any = &contacts[1].rules[0];
+    assert_eq!(any.code, "any_of");
+    let alternatives: Vec<_> = any.rules.iter().map(|r| r.name.as_ref()).collect();
+    assert_eq!(alternatives, ["Email", "Sku"]);
+
+    let schema = &rules[6].rules[0];
+    assert_eq!((schema.name.as_ref(), schema.params["function"].as_ref()), ("schema", "check_product"));
+}
+
+#[derive(validator::Validate)]
+enum Payment {
+    Card {
+        #[validate(Length(equal = 16))]
+        number: String,
+    },
+    Paypal(#[validate(Email(mode = EmailMode::Html5))] String),
+}
+
+#[test]
+fn enum_rules() {
+    assert!(Payment::Card { number: "4111111111111111".into() }.validate().is_ok());
+    assert!(Payment::Paypal("jo".into()).validate().is_err());
+
+    let rules = Payment::validation_rules();
+    assert_eq!(rules[0].field, "Card.number");
+    assert_eq!(rules[1].field, "Paypal.0");
+    assert_eq!(rules[1].rules[0].params["mode"], "html5");
+}
+
+struct NotDefault<T>(std::marker::PhantomData<T>);
+
+impl<T> Default for NotDefault<T> {
+    fn default() -> Self { Self(std::marker::PhantomData) }
+}
+
+impl<T: Default + PartialEq> Validator<T> for NotDefault<T> {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        if *value != T::default() { Ok(()) } else { Err(ValidationError::new("not_default", "Value must be set")) }
+    }
+}
+
+#[derive(validator::Validate)]
+struct Account {
+    #[validate(NotDefault)]
+    name: String,
+    #[validate(each(NotDefault))]
+    ids: Vec<u32>,
+}
+
+#[test]
+fn generic_validator_rules() {
+    assert!(Account { name: "jo".into(), ids: vec![1] }.validate().is_ok());
+    let rules = Account::validation_rules();
+    assert_eq!(rules[0].rules[0], RuleDescriptor::new("NotDefault", ""));
+    assert_eq!(rules[1].rules[0].rules[0], RuleDescriptor::new("NotDefault", ""));
+}
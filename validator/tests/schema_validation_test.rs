@@ -0,0 +1,118 @@
+use validator::prelude::*;
+use validator::validators::*;
+
+#[derive(validator::Validate)]
+#[validate(schema(function = "check_dates"))]
+struct Booking {
+    #[validate(MinLength(3))]
+    name: String,
+    start: u32,
+    end: u32,
+}
+
+fn check_dates(booking: &Booking) -> Result<(), ValidationError> {
+    if booking.end > booking.start {
+        Ok(())
+    } else {
+        Err(ValidationError::new("dates", "end must be after start"))
+    }
+}
+
+#[derive(validator::Validate)]
+#[validate(schema(function = "check_passwords"), schema(function = "check_not_admin"))]
+struct Signup {
+    #[validate(MinLength(8))]
+    password: String,
+    password_confirm: String,
+    username: String,
+}
+
+fn check_passwords(signup: &Signup) -> Result<(), ValidationError> {
+    if signup.password == signup.password_confirm {
+        Ok(())
+    } else {
+        Err(ValidationError::new("must_match", "passwords do not match"))
+    }
+}
+
+fn check_not_admin(signup: &Signup) -> Result<(), ValidationError> {
+    if signup.username == "admin" {
+        Err(ValidationError::new("reserved", "username is reserved"))
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(validator::Validate)]
+#[validate(schema(function = "check_dates_range", skip_on_field_errors))]
+struct Period {
+    #[validate(Positive)]
+    start: i32,
+    end: i32,
+}
+
+fn check_dates_range(period: &Period) -> Result<(), ValidationError> {
+    if period.end > period.start {
+        Ok(())
+    } else {
+        Err(ValidationError::new("dates", "end must be after start"))
+    }
+}
+
+fn booking(name: &str, start: u32, end: u32) -> Booking {
+    Booking { name: name.to_string(), start, end }
+}
+
+#[test]
+fn schema_ok() {
+    let b = booking("Alice", 1, 2);
+    assert!(b.validate().is_ok());
+    assert!(b.complete_validate().is_ok());
+}
+
+#[test]
+fn schema_short_circuit_runs_after_fields() {
+    let err = booking("Al", 2, 1).validate().unwrap_err();
+    assert_eq!(err.code, "min_length");
+
+    let err = booking("Alice", 2, 1).validate().unwrap_err();
+    assert_eq!(err.code, "dates");
+}
+
+#[test]
+fn schema_errors_are_struct_level() {
+    let err = booking("Al", 2, 1).complete_validate().unwrap_err();
+    assert_eq!(err.len(), 2);
+    assert_eq!(err.get("name").map(|v| v[0].code.as_ref()), Some("min_length"));
+    assert_eq!(err.get(ValidationErrors::STRUCT_KEY).map(|v| v[0].code.as_ref()), Some("dates"));
+    assert_eq!(err.errors().len(), 1);
+    assert_eq!(err.to_string(), "__all__: end must be after start\nname: String too short (min 3)");
+}
+
+#[test]
+fn multiple_schemas_collect_all_errors() {
+    let s = Signup {
+        password: "secret".to_string(),
+        password_confirm: "secreT".to_string(),
+        username: "admin".to_string(),
+    };
+    let err = s.validate().unwrap_err();
+    assert_eq!(err.code, "min_length");
+
+    let err = s.complete_validate().unwrap_err();
+    let codes: Vec<_> = err.errors().iter().map(|e| e.code.as_ref()).collect();
+    assert_eq!(codes, vec!["must_match", "reserved"]);
+    assert!(err.contains_key("password"));
+}
+
+#[test]
+fn skip_on_field_errors() {
+    let p = Period { start: -2, end: -3 };
+    let err = p.complete_validate().unwrap_err();
+    assert!(err.contains_key("start"));
+    assert!(err.errors().is_empty());
+
+    let p = Period { start: 2, end: 1 };
+    let err = p.complete_validate().unwrap_err();
+    assert_eq!(err.errors()[0].code, "dates");
+}
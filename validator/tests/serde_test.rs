@@ -0,0 +1,76 @@
+#![cfg(feature = "serde")]
+use validator::prelude::*;
+use validator::validators::*;
+
+#[derive(validator::Validate)]
+struct Address {
+    #[validate(MinLength(3))]
+    street: String,
+}
+
+#[derive(validator::Validate)]
+struct User {
+    #[validate(Email)]
+    email: String,
+    #[validate(each(MaxLength(3)))]
+    tags: Vec<String>,
+    #[validate(nested)]
+    address: Address,
+}
+
+#[test]
+fn validation_error_json_shape() {
+    let err = MaxLength::new(10).validate(&"0123456789123".to_string()).unwrap_err();
+    let json = serde_json::to_value(&err).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "code": "max_length",
+            "message": "String too long (max 10)",
+            "params": { "limit": "10", "len": "13", "mode": "bytes" }
+        })
+    );
+    let back: ValidationError = serde_json::from_value(json).unwrap();
+    assert_eq!(back, err);
+}
+
+#[test]
+fn validation_errors_flat_by_path() {
+    let u = User {
+        email: "invalid@".to_string(),
+        tags: vec!["ok".to_string(), "too long".to_string()],
+        address: Address { street: "Ma".to_string() },
+    };
+    let errors = u.complete_validate().unwrap_err();
+    let json = serde_json::to_string(&errors).unwrap();
+    assert_eq!(
+        json,
+        r#"{"address.street":[{"code":"min_length","message":"String too short (min 3)","params":{"field":"street","len":"2","limit":"3","mode":"bytes"}}],"#.to_string()
+            + r#""email":[{"code":"email","message":"Invalid email format","params":{"field":"email","reason":"format"}}],"#
+            + r#""tags[1]":[{"code":"max_length","message":"String too long (max 3)","params":{"field":"tags","len":"8","limit":"3","mode":"bytes"}}]}"#
+    );
+    let back: ValidationErrors = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, errors);
+}
+
+#[test]
+fn validation_errors_struct_level_and_keys() {
+    let json = r#"{"__all__":[{"code":"schema","message":"Invalid"}],"labels[\"a.b\"]":[{"code":"x","message":"y"}]}"#;
+    let errors: ValidationErrors = serde_json::from_str(json).unwrap();
+    assert_eq!(errors.errors().len(), 1);
+    assert!(errors.contains_key("labels[\"a.b\"]"));
+    assert_eq!(serde_json::to_string(&errors).unwrap(), json.replace("}]", r#","params":{}}]"#));
+
+    assert!(serde_json::from_str::<ValidationErrors>(r#"{"a..b":[]}"#).is_err());
+}
+
+#[test]
+fn message_catalog_from_json() {
+    let catalog: MessageCatalog = serde_json::from_str(
+        r#"{ "fallback": "en", "locales": { "fr": { "email": "Adresse e-mail invalide" }, "en": { "email": "Bad email" } } }"#,
+    )
+    .unwrap();
+    let err = Email::default().validate(&"invalid@".to_string()).unwrap_err();
+    assert_eq!(catalog.resolve("fr", &err), "Adresse e-mail invalide");
+    assert_eq!(catalog.resolve("de", &err), "Bad email");
+}
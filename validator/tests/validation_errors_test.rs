@@ -0,0 +1,76 @@
+use validator::prelude::*;
+use validator::validators::*;
+
+#[derive(validator::Validate)]
+struct Address {
+    #[validate(MinLength(3))]
+    street: String,
+}
+
+#[derive(validator::Validate)]
+struct User {
+    #[validate(MinLength(3), NotAllowedChars(["X"]))]
+    name: String,
+    #[validate(each(Email))]
+    emails: Vec<String>,
+    #[validate(nested)]
+    address: Address,
+}
+
+fn invalid_user() -> User {
+    User {
+        name: "X".to_string(),
+        emails: vec!["john@example.com".to_string(), "invalid@".to_string()],
+        address: Address { street: "Ma".to_string() },
+    }
+}
+
+#[test]
+fn tree_structure() {
+    let err = invalid_user().complete_validate().unwrap_err();
+    assert!(!err.is_empty());
+    assert!(err.errors().is_empty());
+    assert_eq!(err.child(&PathSegment::from("name")).map(|c| c.errors().len()), Some(2));
+    let emails = err.child(&PathSegment::from("emails")).unwrap();
+    assert!(emails.errors().is_empty());
+    assert_eq!(emails.child(&PathSegment::Index(1)).map(|c| c.errors()[0].code.as_ref()), Some("email"));
+    assert!(emails.child(&PathSegment::Index(0)).is_none());
+}
+
+#[test]
+fn deterministic_iteration_by_path() {
+    let err = invalid_user().complete_validate().unwrap_err();
+    let paths: Vec<String> = err.iter().map(|(path, _)| path).collect();
+    assert_eq!(paths, vec!["address.street", "emails[1]", "name"]);
+    assert_eq!(
+        err.to_string(),
+        "address.street: String too short (min 3)\n\
+         emails[1]: Invalid email format\n\
+         name: String too short (min 3)\n\
+         name: Value contains not allowed string"
+    );
+}
+
+#[test]
+fn struct_level_and_merge() {
+    let mut errors = ValidationErrors::new();
+    assert!(errors.is_empty());
+    errors.add_error(ValidationError::new("schema", "Dates are not ordered"));
+    errors.add("end", ValidationError::new("after", "Must be after start"));
+
+    let mut other = ValidationErrors::new();
+    other.add("end", ValidationError::new("required", "Missing"));
+    let mut map = ValidationErrors::new();
+    map.add("value", ValidationError::new("min_length", "Too short"));
+    other.nest(PathSegment::Key("a \"b\"".to_string()), map);
+    other.nest(PathSegment::from("empty"), ValidationErrors::new());
+    errors.merge(other);
+
+    assert_eq!(errors.len(), 3);
+    assert_eq!(errors.get("__all__").map(|v| v.len()), Some(1));
+    assert_eq!(errors.get("end").map(|v| v.len()), Some(2));
+    assert!(errors.contains_key("[\"a \\\"b\\\"\"].value"));
+    assert!(!errors.contains_key("empty"));
+    let paths: Vec<String> = errors.iter().map(|(path, _)| path).collect();
+    assert_eq!(paths, vec!["__all__", "end", "[\"a \\\"b\\\"\"].value"]);
+}